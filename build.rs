@@ -1,3 +1,110 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+macro_rules! ffi_status {
+    ($($(#[doc = $doc:literal])* $name:ident = $code:literal;)*) => {
+        fn write_status(header: &mut String) {
+            $(
+                $(writeln!(header, "/*{} */", $doc).unwrap();)*
+                writeln!(header, "#define {} {}", stringify!($name), $code).unwrap();
+            )*
+        }
+    };
+}
+
+macro_rules! ffi_ops {
+    (
+    $(
+    $name:ident($($input:ident $(: $input_ty:ident)?),+ $(; $($param:ident: $param_ty:ident),+)?)
+    $(-> ($($out:ident $(: $out_ty:ident)?),+))?;
+    )*
+    ) => {
+        fn write_ops(header: &mut String, precision: &str) {
+            $(
+                let mut args = Vec::new();
+                $(args.push(format!("const {} *{}", c_type(stringify!($($input_ty)?), precision), stringify!($input)));)+
+                let outs: &[(&str, &str)] = &[$($((stringify!($out), stringify!($($out_ty)?))),+)?];
+                if outs.is_empty() {
+                    args.push(format!("{} *out", c_type("", precision)));
+                }
+                for (out, ty) in outs {
+                    args.push(format!("{} *{}", c_type(ty, precision), out));
+                }
+                args.push("size_t len".to_string());
+                $($(args.push(format!("{} {}", c_type(stringify!($param_ty), precision), stringify!($param)));)+)?
+                writeln!(header, "int vforce_{}_{}({});", stringify!($name), precision, args.join(", ")).unwrap();
+            )*
+        }
+    };
+}
+
+include!("src/ffi/list.rs");
+
+/// Returns the C type of an element of type `ty` in `src/ffi/list.rs`, in a function of the given
+/// precision.
+fn c_type(ty: &str, precision: &str) -> &'static str {
+    match (ty, precision) {
+        ("" | "AF", "f32") => "float",
+        ("" | "AF", _) => "double",
+        ("Complex", "f32") => "vforce_complex_f32",
+        ("Complex", _) => "vforce_complex_f64",
+        ("i32", _) => "int32_t",
+        _ => panic!("no C type for {}", ty),
+    }
+}
+
+/// Generates the C header declaring the functions of the `ffi` module.
+fn header() -> String {
+    let mut header = String::from(
+        "/* vforce.h - generated by the vforce build script from src/ffi/list.rs; do not edit */\n\
+        \n\
+        #ifndef VFORCE_H\n\
+        #define VFORCE_H\n\
+        \n\
+        #include <stddef.h>\n\
+        #include <stdint.h>\n\
+        \n\
+        #ifdef __cplusplus\n\
+        extern \"C\" {\n\
+        #endif\n\
+        \n",
+    );
+    write_status(&mut header);
+    header.push_str(
+        "\n\
+        typedef struct { float real; float imaginary; } vforce_complex_f32;\n\
+        typedef struct { double real; double imaginary; } vforce_complex_f64;\n",
+    );
+    for precision in ["f32", "f64"] {
+        header.push('\n');
+        write_ops(&mut header, precision);
+    }
+    header.push_str(
+        "\n\
+        #ifdef __cplusplus\n\
+        }\n\
+        #endif\n\
+        \n\
+        #endif /* VFORCE_H */\n",
+    );
+    header
+}
+
 fn main() {
     println!("cargo:rustc-link-lib=framework=Accelerate");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/ffi/list.rs");
+
+    if env::var_os("CARGO_FEATURE_FFI").is_some() {
+        let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+        let header = header();
+        fs::write(out_dir.join("vforce.h"), &header).unwrap();
+        // OUT_DIR is target/<profile>/build/vforce-<hash>/out, and the library is built into
+        // target/<profile>
+        if let Some(profile_dir) = out_dir.ancestors().nth(3) {
+            fs::write(profile_dir.join("vforce.h"), &header).unwrap();
+        }
+    }
 }
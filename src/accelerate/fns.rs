@@ -251,3 +251,38 @@ unsafe extern "C" {
     /// If |x|>1, the result is NaN.
     pub fn vvatanhf(out: *mut f32, input: *const f32, count: *const i32);
 }
+
+// Scalar libm functions from libSystem, for operations VForce does not provide
+#[cfg(target_os = "macos")]
+unsafe extern "C" {
+
+    /// Computes a * b + c with a single rounding
+    pub fn fma(a: f64, b: f64, c: f64) -> f64;
+
+    /// Computes a * b + c with a single rounding
+    pub fn fmaf(a: f32, b: f32, c: f32) -> f32;
+
+    /// Computes the real cube root, which is negative for negative x
+    pub fn cbrt(x: f64) -> f64;
+
+    /// Computes the real cube root, which is negative for negative x
+    pub fn cbrtf(x: f32) -> f32;
+
+    /// Computes the square root, correctly rounded
+    pub fn sqrt(x: f64) -> f64;
+
+    /// Computes the square root, correctly rounded
+    pub fn sqrtf(x: f32) -> f32;
+
+    /// Rounds up to the nearest integer
+    pub fn ceil(x: f64) -> f64;
+
+    /// Rounds up to the nearest integer
+    pub fn ceilf(x: f32) -> f32;
+
+    /// Computes sqrt(x^2 + y^2) without undue overflow or underflow
+    pub fn hypot(x: f64, y: f64) -> f64;
+
+    /// Computes sqrt(x^2 + y^2) without undue overflow or underflow
+    pub fn hypotf(x: f32, y: f32) -> f32;
+}
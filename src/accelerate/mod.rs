@@ -1,6 +1,7 @@
 pub mod fns;
 
 #[repr(C)]
+#[derive(Clone, Copy)]
 /// Represents complex numbers using either single or double precision floats for the real and
 /// imaginary components
 pub struct AccelerateComplex<T: Copy> {
@@ -9,6 +10,11 @@ pub struct AccelerateComplex<T: Copy> {
 }
 
 impl<T: Copy> AccelerateComplex<T> {
+    /// Packs real and imaginary components into a complex number
+    pub const fn new(real: T, imaginary: T) -> Self {
+        Self { real, imaginary }
+    }
+
     /// Unpacks the complex number into its real and imaginary components, in that order, both either single or
     /// double precision floats
     pub fn unpack(&self) -> (T, T) {
@@ -25,8 +25,116 @@ binary_vforce_op!(
     pow_array,
     /// Computes bases ^ exponents, overwriting `bases` with the results.
     pow_array_in_place,
+    /// Computes bases ^ exponents, overwriting `exponents` with the results.
+    pow_array_in_place_rhs,
     accelerate_pow, bases, exponents);
 
+checked_binary_op!(
+    /// Computes bases ^ exponents, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first negative finite base raised to a finite
+    /// non-integer exponent without writing anything.
+    pow_array_checked,
+    /// Computes bases ^ exponents, overwriting `bases` with the results, or returns
+    /// `AccelerateError::DomainError` for the first negative finite base raised to a finite
+    /// non-integer exponent without modifying `bases`.
+    pow_array_in_place_checked,
+    /// Computes bases ^ exponents, overwriting `exponents` with the results, or returns
+    /// `AccelerateError::DomainError` for the first negative finite base raised to a finite
+    /// non-integer exponent without modifying `exponents`.
+    pow_array_in_place_rhs_checked,
+    pow_array, pow_array_in_place, pow_array_in_place_rhs, bases, exponents,
+    crate::domain::BinaryDomain::RealPower);
+
+/// Raises `x` to the integer power `n` by repeated squaring
+fn powi<AF: AccelerateFloat>(x: AF, n: i32) -> AF {
+    let mut base = x;
+    let mut e = n.unsigned_abs();
+    let mut acc = AF::ONE;
+    while e > 0 {
+        if e & 1 == 1 {
+            acc = acc * base;
+        }
+        base = base * base;
+        e >>= 1;
+    }
+    if n < 0 { AF::ONE / acc } else { acc }
+}
+
+/// Raises each element to the integer power `n` by repeated squaring, writing the results to `out`.
+///
+/// For non-negative `n`, exact whenever the result is exactly representable, so for example
+/// squares and cubes of small integers are returned exactly. Negative powers are computed as
+/// 1 / x^-n, which is rounded twice, and gives zero if x^-n overflows even where the result is
+/// representable, as for 2^-1074.
+pub fn powi_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], n: i32
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = powi(x, n);
+    }
+    Ok(())
+}
+
+/// Raises each element to the integer power `n` by repeated squaring, overwriting `input` with
+/// the results.
+///
+/// For non-negative `n`, exact whenever the result is exactly representable, so for example
+/// squares and cubes of small integers are returned exactly. Negative powers are computed as
+/// 1 / x^-n, which is rounded twice, and gives zero if x^-n overflows even where the result is
+/// representable, as for 2^-1074.
+pub fn powi_array_in_place<AF: AccelerateFloat>(input: &mut [AF], n: i32) {
+    for o in input.iter_mut() {
+        *o = powi(*o, n);
+    }
+}
+
+unary_elementwise_op!(
+    /// Computes the cube root of each element, writing the results to `out`.
+    ///
+    /// Unlike pow(x, 1/3), negative inputs give negative results.
+    cbrt_array,
+    /// Computes the cube root of each element, overwriting `input` with the results.
+    ///
+    /// Unlike pow(x, 1/3), negative inputs give negative results.
+    cbrt_array_in_place,
+    /// Computes the cube root of each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    ///
+    /// Unlike pow(x, 1/3), negative inputs give negative results.
+    cbrt_array_masked,
+    |x| x.cbrt());
+
+binary_elementwise_op!(
+    /// Computes sqrt(x^2 + y^2) for each pair of elements without undue overflow or underflow,
+    /// writing the results to `out`.
+    hypot_array,
+    /// Computes sqrt(x^2 + y^2) for each pair of elements without undue overflow or underflow,
+    /// overwriting `x` with the results.
+    hypot_array_in_place,
+    /// Computes sqrt(x^2 + y^2) for each element of `x` and the scalar `y` without undue overflow
+    /// or underflow, writing the results to `out`.
+    hypot_scalar_array,
+    /// Computes sqrt(x^2 + y^2) for each element of `x` and the scalar `y` without undue overflow
+    /// or underflow, overwriting `x` with the results.
+    hypot_scalar_array_in_place,
+    |x, y| x.hypot(y));
+
+binary_elementwise_op!(
+    /// Computes 1/sqrt(x^2 + y^2) for each pair of elements without undue overflow or underflow,
+    /// writing the results to `out`.
+    rhypot_array,
+    /// Computes 1/sqrt(x^2 + y^2) for each pair of elements without undue overflow or underflow,
+    /// overwriting `x` with the results.
+    rhypot_array_in_place,
+    /// Computes 1/sqrt(x^2 + y^2) for each element of `x` and the scalar `y` without undue
+    /// overflow or underflow, writing the results to `out`.
+    rhypot_scalar_array,
+    /// Computes 1/sqrt(x^2 + y^2) for each element of `x` and the scalar `y` without undue
+    /// overflow or underflow, overwriting `x` with the results.
+    rhypot_scalar_array_in_place,
+    |x, y| AF::ONE / x.hypot(y));
+
 // ── Arithmetic and Auxiliary Functions ──
 
 binary_vforce_op!(
@@ -34,6 +142,8 @@ binary_vforce_op!(
     div_array,
     /// Computes numerator / denominator, overwriting `numerator` with the results.
     div_array_in_place,
+    /// Computes numerator / denominator, overwriting `denominator` with the results.
+    div_array_in_place_rhs,
     accelerate_div, numerator, denominator);
 
 binary_vforce_op!(
@@ -43,6 +153,9 @@ binary_vforce_op!(
     /// Copies the sign of each element in `sign` to the corresponding element in `magnitude`,
     /// overwriting `magnitude` with the results.
     copysign_array_in_place,
+    /// Copies the sign of each element in `sign` to the corresponding element in `magnitude`,
+    /// overwriting `sign` with the results.
+    copysign_array_in_place_rhs,
     accelerate_copysign, magnitude, sign);
 
 binary_vforce_op!(
@@ -52,8 +165,27 @@ binary_vforce_op!(
     /// Computes the floating-point remainder of numerator / denominator (C fmod),
     /// overwriting `numerator` with the results.
     fmod_array_in_place,
+    /// Computes the floating-point remainder of numerator / denominator (C fmod),
+    /// overwriting `denominator` with the results.
+    fmod_array_in_place_rhs,
     accelerate_fmod, numerator, denominator);
 
+checked_binary_op!(
+    /// Computes the floating-point remainder of numerator / denominator (C fmod), writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first zero denominator or infinite numerator
+    /// without writing anything.
+    fmod_array_checked,
+    /// Computes the floating-point remainder of numerator / denominator (C fmod), overwriting `numerator` with the results, or
+    /// returns `AccelerateError::DomainError` for the first zero denominator or infinite
+    /// numerator without modifying `numerator`.
+    fmod_array_in_place_checked,
+    /// Computes the floating-point remainder of numerator / denominator (C fmod), overwriting `denominator` with the results, or
+    /// returns `AccelerateError::DomainError` for the first zero denominator or infinite
+    /// numerator without modifying `denominator`.
+    fmod_array_in_place_rhs_checked,
+    fmod_array, fmod_array_in_place, fmod_array_in_place_rhs, numerator, denominator,
+    crate::domain::BinaryDomain::NonZeroDivisor);
+
 binary_vforce_op!(
     /// Computes the IEEE remainder of numerator / denominator,
     /// writing the results to `out`.
@@ -61,8 +193,27 @@ binary_vforce_op!(
     /// Computes the IEEE remainder of numerator / denominator,
     /// overwriting `numerator` with the results.
     remainder_array_in_place,
+    /// Computes the IEEE remainder of numerator / denominator,
+    /// overwriting `denominator` with the results.
+    remainder_array_in_place_rhs,
     accelerate_remainder, numerator, denominator);
 
+checked_binary_op!(
+    /// Computes the IEEE remainder of numerator / denominator, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first zero denominator or infinite numerator
+    /// without writing anything.
+    remainder_array_checked,
+    /// Computes the IEEE remainder of numerator / denominator, overwriting `numerator` with the results, or
+    /// returns `AccelerateError::DomainError` for the first zero denominator or infinite
+    /// numerator without modifying `numerator`.
+    remainder_array_in_place_checked,
+    /// Computes the IEEE remainder of numerator / denominator, overwriting `denominator` with the results, or
+    /// returns `AccelerateError::DomainError` for the first zero denominator or infinite
+    /// numerator without modifying `denominator`.
+    remainder_array_in_place_rhs_checked,
+    remainder_array, remainder_array_in_place, remainder_array_in_place_rhs, numerator, denominator,
+    crate::domain::BinaryDomain::NonZeroDivisor);
+
 binary_vforce_op!(
     /// Computes the next representable floating-point value after each element in `input`
     /// in the direction of the corresponding element in `direction`, writing the results to `out`.
@@ -71,6 +222,10 @@ binary_vforce_op!(
     /// in the direction of the corresponding element in `direction`,
     /// overwriting `input` with the results.
     nextafter_array_in_place,
+    /// Computes the next representable floating-point value after each element in `input`
+    /// in the direction of the corresponding element in `direction`,
+    /// overwriting `direction` with the results.
+    nextafter_array_in_place_rhs,
     accelerate_nextafter, input, direction);
 
 unary_vforce_op!(
@@ -78,6 +233,9 @@ unary_vforce_op!(
     ceil_array,
     /// Computes the ceiling of each element, overwriting `input` with the results.
     ceil_array_in_place,
+    /// Computes the ceiling of each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    ceil_array_masked,
     accelerate_ceil, input);
 
 unary_vforce_op!(
@@ -85,6 +243,9 @@ unary_vforce_op!(
     floor_array,
     /// Computes the floor of each element, overwriting `input` with the results.
     floor_array_in_place,
+    /// Computes the floor of each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    floor_array_masked,
     accelerate_floor, input);
 
 unary_vforce_op!(
@@ -92,6 +253,9 @@ unary_vforce_op!(
     fabs_array,
     /// Computes the absolute value of each element, overwriting `input` with the results.
     fabs_array_in_place,
+    /// Computes the absolute value of each element, writing the results to `out` where `mask` is
+    /// set and leaving the other elements of `out` unchanged.
+    fabs_array_masked,
     accelerate_fabs, input);
 
 unary_vforce_op!(
@@ -99,13 +263,28 @@ unary_vforce_op!(
     int_array,
     /// Truncates each element to an integer (rounds toward zero), overwriting `input` with the results.
     int_array_in_place,
+    /// Truncates each element to an integer (rounds toward zero), writing the results to `out`
+    /// where `mask` is set and leaving the other elements of `out` unchanged.
+    int_array_masked,
     accelerate_int, input);
 
 unary_vforce_op!(
     /// Rounds each element to the nearest integer, writing the results to `out`.
+    ///
+    /// Ties are rounded to the nearest even integer, as in IEEE 754 roundTiesToEven, so
+    /// 0.5 and 2.5 give 0 and 2. Use [`round_half_away_array`] to round ties away from zero.
     nint_array,
     /// Rounds each element to the nearest integer, overwriting `input` with the results.
+    ///
+    /// Ties are rounded to the nearest even integer, as in IEEE 754 roundTiesToEven, so
+    /// 0.5 and 2.5 give 0 and 2. Use [`round_half_away_array_in_place`] to round ties away from zero.
     nint_array_in_place,
+    /// Rounds each element to the nearest integer, writing the results to `out` where `mask` is
+    /// set and leaving the other elements of `out` unchanged.
+    ///
+    /// Ties are rounded to the nearest even integer, as in IEEE 754 roundTiesToEven, so
+    /// 0.5 and 2.5 give 0 and 2. Use [`round_half_away_array`] to round ties away from zero.
+    nint_array_masked,
     accelerate_nint, input);
 
 unary_vforce_op!(
@@ -113,18 +292,692 @@ unary_vforce_op!(
     rsqrt_array,
     /// Computes 1/sqrt(x) for each element, overwriting `input` with the results.
     rsqrt_array_in_place,
+    /// Computes 1/sqrt(x) for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    rsqrt_array_masked,
     accelerate_rsqrt, input);
 
+checked_unary_op!(
+    /// Computes 1/sqrt(x) for each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without writing anything.
+    rsqrt_array_checked,
+    /// Computes 1/sqrt(x) for each element, overwriting `input` with the results, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without modifying `input`.
+    rsqrt_array_in_place_checked,
+    rsqrt_array, rsqrt_array_in_place, crate::domain::Domain::NonNegative);
+
 unary_vforce_op!(
     /// Computes the square root of each element, writing the results to `out`.
     sqrt_array,
     /// Computes the square root of each element, overwriting `input` with the results.
     sqrt_array_in_place,
+    /// Computes the square root of each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    sqrt_array_masked,
     accelerate_sqrt, input);
 
+checked_unary_op!(
+    /// Computes the square root of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without writing anything.
+    sqrt_array_checked,
+    /// Computes the square root of each element, overwriting `input` with the results, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without modifying `input`.
+    sqrt_array_in_place_checked,
+    sqrt_array, sqrt_array_in_place, crate::domain::Domain::NonNegative);
+
 unary_vforce_op!(
     /// Computes 1/x for each element, writing the results to `out`.
     rec_array,
     /// Computes 1/x for each element, overwriting `input` with the results.
     rec_array_in_place,
+    /// Computes 1/x for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    rec_array_masked,
     accelerate_rec, input);
+
+// ── Elementwise arithmetic ──
+//
+// VForce has no plain arithmetic beyond division, so these are implemented as straight loops
+// which the compiler vectorizes.
+
+binary_elementwise_op!(
+    /// Computes a + b for each pair of elements, writing the results to `out`.
+    add_array,
+    /// Computes a + b for each pair of elements, overwriting `a` with the results.
+    add_array_in_place,
+    /// Adds the scalar `b` to each element of `a`, writing the results to `out`.
+    add_scalar_array,
+    /// Adds the scalar `b` to each element of `a`, overwriting `a` with the results.
+    add_scalar_array_in_place,
+    |a, b| a + b);
+
+binary_elementwise_op!(
+    /// Computes a - b for each pair of elements, writing the results to `out`.
+    sub_array,
+    /// Computes a - b for each pair of elements, overwriting `a` with the results.
+    sub_array_in_place,
+    /// Subtracts the scalar `b` from each element of `a`, writing the results to `out`.
+    sub_scalar_array,
+    /// Subtracts the scalar `b` from each element of `a`, overwriting `a` with the results.
+    sub_scalar_array_in_place,
+    |a, b| a - b);
+
+binary_elementwise_op!(
+    /// Computes a * b for each pair of elements, writing the results to `out`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vforce::arithmetic::{mul_array, mul_scalar_array_in_place};
+    /// let a: Vec<f64> = vec![1.0, 2.0, 3.0];
+    /// let b: Vec<f64> = vec![4.0, 5.0, 6.0];
+    ///
+    /// let mut out = vec![0.0f64; 3];
+    /// mul_array(&mut out, &a, &b).unwrap();
+    /// assert_eq!(out, vec![4.0, 10.0, 18.0]);
+    /// // a scalar may be broadcast over the whole array:
+    /// mul_scalar_array_in_place(&mut out, 0.5);
+    /// assert_eq!(out, vec![2.0, 5.0, 9.0]);
+    /// ```
+    mul_array,
+    /// Computes a * b for each pair of elements, overwriting `a` with the results.
+    mul_array_in_place,
+    /// Multiplies each element of `a` by the scalar `b`, writing the results to `out`.
+    mul_scalar_array,
+    /// Multiplies each element of `a` by the scalar `b`, overwriting `a` with the results.
+    mul_scalar_array_in_place,
+    |a, b| a * b);
+
+binary_elementwise_op!(
+    /// Computes |a - b| for each pair of elements, writing the results to `out`.
+    abs_diff_array,
+    /// Computes |a - b| for each pair of elements, overwriting `a` with the results.
+    abs_diff_array_in_place,
+    /// Computes |a - b| between each element of `a` and the scalar `b`, writing the results to `out`.
+    abs_diff_scalar_array,
+    /// Computes |a - b| between each element of `a` and the scalar `b`, overwriting `a` with the results.
+    abs_diff_scalar_array_in_place,
+    |a, b| (a - b).abs());
+
+binary_elementwise_op!(
+    /// Computes the minimum of each pair of elements, writing the results to `out`.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    min_array,
+    /// Computes the minimum of each pair of elements, overwriting `a` with the results.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    min_array_in_place,
+    /// Computes the minimum of each element of `a` and the scalar `b`, writing the results to `out`.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    min_scalar_array,
+    /// Computes the minimum of each element of `a` and the scalar `b`, overwriting `a` with the results.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    min_scalar_array_in_place,
+    |a, b| a.min(b));
+
+binary_elementwise_op!(
+    /// Computes the maximum of each pair of elements, writing the results to `out`.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    max_array,
+    /// Computes the maximum of each pair of elements, overwriting `a` with the results.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    max_array_in_place,
+    /// Computes the maximum of each element of `a` and the scalar `b`, writing the results to `out`.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    max_scalar_array,
+    /// Computes the maximum of each element of `a` and the scalar `b`, overwriting `a` with the results.
+    ///
+    /// If exactly one of the pair is NaN, the other is returned.
+    max_scalar_array_in_place,
+    |a, b| a.max(b));
+
+unary_elementwise_op!(
+    /// Negates each element, writing the results to `out`.
+    neg_array,
+    /// Negates each element, overwriting `input` with the results.
+    neg_array_in_place,
+    /// Negates each element, writing the results to `out` where `mask` is set and leaving the
+    /// other elements of `out` unchanged.
+    neg_array_masked,
+    |x| -x);
+
+unary_elementwise_op!(
+    /// Computes x * x for each element, writing the results to `out`.
+    sqr_array,
+    /// Computes x * x for each element, overwriting `input` with the results.
+    sqr_array_in_place,
+    /// Computes x * x for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    sqr_array_masked,
+    |x| x * x);
+
+ternary_elementwise_op!(
+    /// Computes a * b + c for each triple of elements with a single rounding, writing the results to `out`.
+    fma_array,
+    /// Computes a * b + c for each triple of elements with a single rounding, overwriting `a` with the results.
+    fma_array_in_place,
+    |a, b, c| a.mul_add(b, c));
+
+ternary_elementwise_op!(
+    /// Clamps each element of `input` to the range [lo, hi] given by the corresponding elements
+    /// of `lo` and `hi`, writing the results to `out`.
+    ///
+    /// NaN elements of `input` are passed through unchanged.
+    clamp_array,
+    /// Clamps each element of `input` to the range [lo, hi] given by the corresponding elements
+    /// of `lo` and `hi`, overwriting `input` with the results.
+    ///
+    /// NaN elements of `input` are passed through unchanged.
+    clamp_array_in_place,
+    |input, lo, hi| if input < lo { lo } else if input > hi { hi } else { input });
+
+ternary_elementwise_op!(
+    /// Linearly interpolates between `a` and `b` by `t`, computed as (1 - t) * a + t * b so that
+    /// t = 0 and t = 1 return `a` and `b` exactly, writing the results to `out`.
+    lerp_array,
+    /// Linearly interpolates between `a` and `b` by `t`, computed as (1 - t) * a + t * b so that
+    /// t = 0 and t = 1 return `a` and `b` exactly, overwriting `a` with the results.
+    lerp_array_in_place,
+    |a, b, t| (AF::ONE - t) * a + t * b);
+
+/// Computes a * b + c for each element of `a` with scalar `b` and `c` and a single rounding,
+/// writing the results to `out`.
+pub fn fma_scalar_array<AF: AccelerateFloat>(
+    out: &mut [AF], a: &[AF], b: AF, c: AF
+) -> Result<(), AccelerateError> {
+    check_lengths!(a, out)?;
+    for (o, &a) in out.iter_mut().zip(a) {
+        *o = a.mul_add(b, c);
+    }
+    Ok(())
+}
+
+/// Computes a * b + c for each element of `a` with scalar `b` and `c` and a single rounding,
+/// overwriting `a` with the results.
+pub fn fma_scalar_array_in_place<AF: AccelerateFloat>(a: &mut [AF], b: AF, c: AF) {
+    for o in a.iter_mut() {
+        *o = o.mul_add(b, c);
+    }
+}
+
+/// Clamps each element of `input` to the scalar range [lo, hi], writing the results to `out`.
+///
+/// NaN elements of `input` are passed through unchanged.
+pub fn clamp_scalar_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], lo: AF, hi: AF
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = if x < lo { lo } else if x > hi { hi } else { x };
+    }
+    Ok(())
+}
+
+/// Clamps each element of `input` to the scalar range [lo, hi], overwriting `input` with the results.
+///
+/// NaN elements of `input` are passed through unchanged.
+pub fn clamp_scalar_array_in_place<AF: AccelerateFloat>(input: &mut [AF], lo: AF, hi: AF) {
+    for o in input.iter_mut() {
+        if *o < lo { *o = lo } else if *o > hi { *o = hi }
+    }
+}
+
+/// Linearly interpolates between `a` and `b` by the scalar `t`, writing the results to `out`.
+pub fn lerp_scalar_array<AF: AccelerateFloat>(
+    out: &mut [AF], a: &[AF], b: &[AF], t: AF
+) -> Result<(), AccelerateError> {
+    check_lengths!(a, b, out)?;
+    for (o, (&a, &b)) in out.iter_mut().zip(a.iter().zip(b)) {
+        *o = (AF::ONE - t) * a + t * b;
+    }
+    Ok(())
+}
+
+/// Linearly interpolates between `a` and `b` by the scalar `t`, overwriting `a` with the results.
+pub fn lerp_scalar_array_in_place<AF: AccelerateFloat>(
+    a: &mut [AF], b: &[AF], t: AF
+) -> Result<(), AccelerateError> {
+    check_lengths!(a, b)?;
+    for (o, &b) in a.iter_mut().zip(b) {
+        *o = (AF::ONE - t) * *o + t * b;
+    }
+    Ok(())
+}
+
+// ── Float decomposition ──
+//
+// These work on the bit representation of an f64, which holds every f32 exactly, so both
+// precisions share one implementation.
+
+/// Returns 2^n for n in [-1022, 1023]
+fn pow2(n: i32) -> f64 {
+    f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+/// Splits `x` into a mantissa in [0.5, 1) and an exponent such that x = mantissa * 2^exponent.
+/// Zeros, infinities and NaN are returned unchanged with an exponent of 0.
+fn frexp(x: f64) -> (f64, i32) {
+    if x == 0.0 || !x.is_finite() {
+        return (x, 0);
+    }
+    // bring subnormals into the normal range so the exponent field is meaningful
+    let (x, adjust) = if x.abs() < f64::MIN_POSITIVE { (x * pow2(64), -64) } else { (x, 0) };
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (0x3fe << 52));
+    (mantissa, biased - 0x3fe + adjust)
+}
+
+/// Computes x * 2^n without computing 2^n itself, so that it cannot overflow or underflow early,
+/// and with a single rounding in the subnormal range.
+fn ldexp(x: f64, n: i32) -> f64 {
+    let mut y = x;
+    let mut n = n;
+    if n > 1023 {
+        y *= pow2(1023);
+        n -= 1023;
+        if n > 1023 {
+            y *= pow2(1023);
+            n -= 1023;
+            n = n.min(1023);
+        }
+    } else if n < -1022 {
+        // leave n below -53 afterwards so the final product is the only rounding
+        y *= pow2(-1022 + 53);
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= pow2(-1022 + 53);
+            n += 1022 - 53;
+            n = n.max(-1022);
+        }
+    }
+    y * pow2(n)
+}
+
+/// Returns the unbiased exponent of `x`, with i32::MIN for zero and NaN and i32::MAX for infinities
+fn ilogb(x: f64) -> i32 {
+    if x.is_nan() || x == 0.0 {
+        i32::MIN
+    } else if x.is_infinite() {
+        i32::MAX
+    } else {
+        frexp(x).1 - 1
+    }
+}
+
+/// Splits each element into a mantissa with magnitude in [0.5, 1) and an integral exponent, such
+/// that x = mantissa * 2^exponent, writing them to `mantissa_out` and `exponent_out` respectively.
+///
+/// Zeros, infinities and NaN are written unchanged to `mantissa_out` with an exponent of 0.
+///
+/// # Example
+///
+/// ```rust
+/// use vforce::arithmetic::{frexp_array, ldexp_array};
+/// let input: Vec<f64> = vec![8.0, -0.375, 1.0];
+/// let mut mantissas = vec![0.0f64; 3];
+/// let mut exponents = vec![0i32; 3];
+///
+/// frexp_array(&mut mantissas, &mut exponents, &input).unwrap();
+/// assert_eq!(mantissas, vec![0.5, -0.75, 0.5]);
+/// assert_eq!(exponents, vec![4, -1, 1]);
+/// // and recombine them:
+/// let mut out = vec![0.0f64; 3];
+/// ldexp_array(&mut out, &mantissas, &exponents).unwrap();
+/// assert_eq!(out, input);
+/// ```
+pub fn frexp_array<AF: AccelerateFloat>(
+    mantissa_out: &mut [AF], exponent_out: &mut [i32], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, mantissa_out, exponent_out)?;
+    for ((m, e), &x) in mantissa_out.iter_mut().zip(exponent_out.iter_mut()).zip(input) {
+        let (mantissa, exponent) = frexp(x.to_f64());
+        *m = AF::from_f64(mantissa);
+        *e = exponent;
+    }
+    Ok(())
+}
+
+/// Splits each element into a mantissa with magnitude in [0.5, 1) and an integral exponent, such
+/// that x = mantissa * 2^exponent, writing the mantissas into `input` and the exponents into
+/// `exponent_out`.
+///
+/// Zeros, infinities and NaN are left unchanged with an exponent of 0.
+pub fn frexp_array_in_place<AF: AccelerateFloat>(
+    exponent_out: &mut [i32], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, exponent_out)?;
+    for (x, e) in input.iter_mut().zip(exponent_out.iter_mut()) {
+        let (mantissa, exponent) = frexp(x.to_f64());
+        *x = AF::from_f64(mantissa);
+        *e = exponent;
+    }
+    Ok(())
+}
+
+/// Computes x * 2^n for each element of `input` and corresponding element of `exponents`,
+/// writing the results to `out`.
+///
+/// Intermediate results never overflow or underflow, and the result is rounded only once.
+pub fn ldexp_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], exponents: &[i32]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, exponents, out)?;
+    for (o, (&x, &n)) in out.iter_mut().zip(input.iter().zip(exponents)) {
+        *o = AF::from_f64(ldexp(x.to_f64(), n));
+    }
+    Ok(())
+}
+
+/// Computes x * 2^n for each element of `input` and corresponding element of `exponents`,
+/// overwriting `input` with the results.
+///
+/// Intermediate results never overflow or underflow, and the result is rounded only once.
+pub fn ldexp_array_in_place<AF: AccelerateFloat>(
+    input: &mut [AF], exponents: &[i32]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, exponents)?;
+    for (x, &n) in input.iter_mut().zip(exponents) {
+        *x = AF::from_f64(ldexp(x.to_f64(), n));
+    }
+    Ok(())
+}
+
+/// Computes x * 2^n for each element of `input` and the scalar `n`, writing the results to `out`.
+///
+/// Intermediate results never overflow or underflow, and the result is rounded only once.
+pub fn scalbn_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], n: i32
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = AF::from_f64(ldexp(x.to_f64(), n));
+    }
+    Ok(())
+}
+
+/// Computes x * 2^n for each element of `input` and the scalar `n`, overwriting `input` with
+/// the results.
+///
+/// Intermediate results never overflow or underflow, and the result is rounded only once.
+pub fn scalbn_array_in_place<AF: AccelerateFloat>(input: &mut [AF], n: i32) {
+    for x in input.iter_mut() {
+        *x = AF::from_f64(ldexp(x.to_f64(), n));
+    }
+}
+
+/// Extracts the unbiased binary exponent of each element as an integer, writing the results to
+/// `out`, so that 2^exponent <= |x| < 2^(exponent + 1).
+///
+/// Zero and NaN give `i32::MIN`, and +/-inf gives `i32::MAX`.
+///
+/// Does not have an in-place variant, as the output is of a different type to the input.
+pub fn ilogb_array<AF: AccelerateFloat>(
+    out: &mut [i32], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = ilogb(x.to_f64());
+    }
+    Ok(())
+}
+
+/// Computes the fractional part x - trunc(x), given `int` = trunc(x), with the sign of x.
+/// Infinities have a fractional part of zero rather than NaN.
+fn frac_part<AF: AccelerateFloat>(x: AF, int: AF) -> AF {
+    let frac = if x.to_f64().is_infinite() { AF::ZERO } else { x - int };
+    AF::from_f64(frac.to_f64().copysign(x.to_f64()))
+}
+
+/// Splits each element into its integral part, rounded toward zero, and its fractional part,
+/// both with the sign of the input, writing them to `int_out` and `frac_out` respectively.
+///
+/// Infinities have a fractional part of zero.
+pub fn modf_array<AF: AccelerateFloat>(
+    int_out: &mut [AF], frac_out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, int_out, frac_out)?;
+    int_array(int_out, input)?;
+    for (f, (&i, &x)) in frac_out.iter_mut().zip(int_out.iter().zip(input)) {
+        *f = frac_part(x, i);
+    }
+    Ok(())
+}
+
+/// Splits each element into its integral part, rounded toward zero, and its fractional part,
+/// both with the sign of the input, writing the integral parts into `input` and the fractional
+/// parts into `frac_out`.
+///
+/// Infinities have a fractional part of zero.
+pub fn modf_array_in_place_int<AF: AccelerateFloat>(
+    frac_out: &mut [AF], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, frac_out)?;
+    frac_out.copy_from_slice(input);
+    int_array_in_place(input);
+    for (f, &i) in frac_out.iter_mut().zip(input.iter()) {
+        *f = frac_part(*f, i);
+    }
+    Ok(())
+}
+
+/// Splits each element into its integral part, rounded toward zero, and its fractional part,
+/// both with the sign of the input, writing the fractional parts into `input` and the integral
+/// parts into `int_out`.
+///
+/// Infinities have a fractional part of zero.
+pub fn modf_array_in_place_frac<AF: AccelerateFloat>(
+    int_out: &mut [AF], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, int_out)?;
+    int_array(int_out, input)?;
+    for (x, &i) in input.iter_mut().zip(int_out.iter()) {
+        *x = frac_part(*x, i);
+    }
+    Ok(())
+}
+
+// ── Rounding ──
+//
+// Implemented on the f64 representation without reference to the floating-point environment,
+// so that tie behaviour is identical everywhere.
+
+/// 2^52, above which every f64 is an integer
+const TWO_POW_52: f64 = 4503599627370496.0;
+
+/// Rounds toward zero. Values of 2^52 and above, infinities and NaN are returned unchanged.
+fn trunc(x: f64) -> f64 {
+    if x.abs() < TWO_POW_52 { ((x as i64) as f64).copysign(x) } else { x }
+}
+
+/// Rounds to the nearest integer, with ties rounded away from zero
+fn round_half_away(x: f64) -> f64 {
+    let t = trunc(x);
+    if (x - t).abs() >= 0.5 { t + 1.0f64.copysign(x) } else { t }
+}
+
+/// Rounds to the nearest integer, with ties rounded to the nearest even integer
+fn round_half_even(x: f64) -> f64 {
+    let t = trunc(x);
+    let diff = (x - t).abs();
+    if diff > 0.5 || (diff == 0.5 && (t as i64) % 2 != 0) { t + 1.0f64.copysign(x) } else { t }
+}
+
+/// Rounds to an integer in the current rounding mode, by adding and subtracting 2^52 so the
+/// hardware performs the rounding.
+///
+/// The shift is taken towards the sign of x, so that x itself rather than |x| is rounded, as
+/// directed rounding modes round negative values the other way. The result takes the sign of x,
+/// as a zero result may otherwise come out as -0 when rounding downwards.
+fn rint(x: f64) -> f64 {
+    if x.abs() < TWO_POW_52 {
+        let r = if x.is_sign_negative() { (x - TWO_POW_52) + TWO_POW_52 } else { (x + TWO_POW_52) - TWO_POW_52 };
+        r.copysign(x)
+    } else {
+        x
+    }
+}
+
+/// Rounds `x` to the nearest multiple of `scale`, with ties to even, if `divide` is set, or to the
+/// nearest multiple of 1 / `scale` otherwise. Values too large to have a fractional part at that
+/// scale are returned unchanged.
+fn round_scaled(x: f64, scale: f64, divide: bool) -> f64 {
+    if !x.is_finite() {
+        return x;
+    }
+    if scale.is_infinite() {
+        return if divide { 0.0f64.copysign(x) } else { x };
+    }
+    let scaled = if divide { x / scale } else { x * scale };
+    if scaled.is_nan() || scaled.abs() >= TWO_POW_52 {
+        return x;
+    }
+    let rounded = round_half_even(scaled);
+    let result = if divide { rounded * scale } else { rounded / scale };
+    result.copysign(x)
+}
+
+unary_elementwise_op!(
+    /// Rounds each element to the nearest integer, with ties rounded away from zero (IEEE 754
+    /// roundTiesToAway, as in C `round`), writing the results to `out`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use vforce::arithmetic::{round_half_away_array, round_half_even_array};
+    /// let input: Vec<f64> = vec![0.5, 1.5, 2.5, -2.5, 2.4];
+    /// let mut out = vec![0.0f64; 5];
+    ///
+    /// round_half_away_array(&mut out, &input).unwrap();
+    /// assert_eq!(out, vec![1.0, 2.0, 3.0, -3.0, 2.0]);
+    /// round_half_even_array(&mut out, &input).unwrap();
+    /// assert_eq!(out, vec![0.0, 2.0, 2.0, -2.0, 2.0]);
+    /// ```
+    round_half_away_array,
+    /// Rounds each element to the nearest integer, with ties rounded away from zero (IEEE 754
+    /// roundTiesToAway, as in C `round`), overwriting `input` with the results.
+    round_half_away_array_in_place,
+    /// Rounds each element to the nearest integer, with ties rounded away from zero (IEEE 754
+    /// roundTiesToAway, as in C `round`), writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    round_half_away_array_masked,
+    |x| AF::from_f64(round_half_away(x.to_f64())));
+
+unary_elementwise_op!(
+    /// Rounds each element to the nearest integer, with ties rounded to the nearest even integer
+    /// (IEEE 754 roundTiesToEven), writing the results to `out`.
+    ///
+    /// Unlike [`rint_array`], this does not depend on the floating-point environment.
+    round_half_even_array,
+    /// Rounds each element to the nearest integer, with ties rounded to the nearest even integer
+    /// (IEEE 754 roundTiesToEven), overwriting `input` with the results.
+    ///
+    /// Unlike [`rint_array_in_place`], this does not depend on the floating-point environment.
+    round_half_even_array_in_place,
+    /// Rounds each element to the nearest integer, with ties rounded to the nearest even integer
+    /// (IEEE 754 roundTiesToEven), writing the results to `out` where `mask` is set and leaving
+    /// the other elements of `out` unchanged.
+    ///
+    /// Unlike [`rint_array`], this does not depend on the floating-point environment.
+    round_half_even_array_masked,
+    |x| AF::from_f64(round_half_even(x.to_f64())));
+
+unary_elementwise_op!(
+    /// Rounds each element to an integer in the current rounding mode, as C `rint`, writing the
+    /// results to `out`.
+    ///
+    /// Rust code runs in the default round-to-nearest mode, where this is identical to
+    /// [`round_half_even_array`]; it only differs if foreign code has changed the rounding mode.
+    rint_array,
+    /// Rounds each element to an integer in the current rounding mode, as C `rint`, overwriting
+    /// `input` with the results.
+    ///
+    /// Rust code runs in the default round-to-nearest mode, where this is identical to
+    /// [`round_half_even_array_in_place`]; it only differs if foreign code has changed the
+    /// rounding mode.
+    rint_array_in_place,
+    /// Rounds each element to an integer in the current rounding mode, as C `rint`, writing the
+    /// results to `out` where `mask` is set and leaving the other elements of `out` unchanged.
+    ///
+    /// Rust code runs in the default round-to-nearest mode, where this is identical to
+    /// [`round_half_even_array`]; it only differs if foreign code has changed the rounding mode.
+    rint_array_masked,
+    |x| AF::from_f64(rint(x.to_f64())));
+
+/// Rounds each element to the nearest multiple of `step`, writing the results to `out`.
+///
+/// Computed as round(x / step) * step, with ties rounded to even. As x / step is itself
+/// rounded, results and ties are those of the scaled value; for example, rounding 0.3 to a
+/// multiple of 0.1 gives 3 * 0.1 = 0.30000000000000004.
+pub fn round_to_multiple_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], step: AF
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    let step = step.to_f64();
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = AF::from_f64(round_scaled(x.to_f64(), step, true));
+    }
+    Ok(())
+}
+
+/// Rounds each element to the nearest multiple of `step`, overwriting `input` with the results.
+///
+/// Computed as round(x / step) * step, with ties rounded to even. As x / step is itself
+/// rounded, results and ties are those of the scaled value.
+pub fn round_to_multiple_array_in_place<AF: AccelerateFloat>(input: &mut [AF], step: AF) {
+    let step = step.to_f64();
+    for x in input.iter_mut() {
+        *x = AF::from_f64(round_scaled(x.to_f64(), step, true));
+    }
+}
+
+/// Scales by 10^|decimals| in the direction that keeps the power of ten exact where possible
+fn round_decimals(x: f64, decimals: i32) -> f64 {
+    let scale = powi(10.0f64, decimals.saturating_abs());
+    round_scaled(x, scale, decimals < 0)
+}
+
+/// Rounds each element to `decimals` decimal places, writing the results to `out`. Negative
+/// `decimals` round to the left of the decimal point, so -2 rounds to a multiple of 100.
+///
+/// Ties are rounded to even, as in NumPy's `round`. Ties are decided on the scaled value
+/// x * 10^decimals after it has itself been rounded to the nearest float, so values which are not
+/// exactly representable, such as 2.675, may round either way.
+///
+/// # Example
+///
+/// ```rust
+/// use vforce::arithmetic::round_to_decimals_array;
+/// let input: Vec<f64> = vec![1.23456, 1234.5, -0.125];
+/// let mut out = vec![0.0f64; 3];
+///
+/// round_to_decimals_array(&mut out, &input, 2).unwrap();
+/// assert_eq!(out, vec![1.23, 1234.5, -0.12]);
+/// round_to_decimals_array(&mut out, &input, -2).unwrap();
+/// assert_eq!(out, vec![0.0, 1200.0, -0.0]);
+/// ```
+pub fn round_to_decimals_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], decimals: i32
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    for (o, &x) in out.iter_mut().zip(input) {
+        *o = AF::from_f64(round_decimals(x.to_f64(), decimals));
+    }
+    Ok(())
+}
+
+/// Rounds each element to `decimals` decimal places, overwriting `input` with the results.
+/// Negative `decimals` round to the left of the decimal point, so -2 rounds to a multiple of 100.
+///
+/// Ties are rounded to even, as in NumPy's `round`, and are decided on the scaled binary value.
+pub fn round_to_decimals_array_in_place<AF: AccelerateFloat>(input: &mut [AF], decimals: i32) {
+    for x in input.iter_mut() {
+        *x = AF::from_f64(round_decimals(x.to_f64(), decimals));
+    }
+}
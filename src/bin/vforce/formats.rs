@@ -0,0 +1,205 @@
+//! Reading and writing of CSV, `.npy` and raw little-endian binary files.
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use vforce::npy::{NpyArray, NpyError};
+
+use crate::Precision;
+
+/// File format, chosen by extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Npy,
+    Raw,
+}
+
+impl Format {
+    pub fn of(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(e) if e.eq_ignore_ascii_case("csv") => Self::Csv,
+            Some(e) if e.eq_ignore_ascii_case("npy") => Self::Npy,
+            _ => Self::Raw,
+        }
+    }
+}
+
+/// Numeric data as a set of equal-length columns, with what is needed to write it back in the
+/// shape it was read in
+pub struct Data<T> {
+    pub columns: Vec<Vec<T>>,
+    /// Column names from the first line of a CSV file
+    pub header: Option<Vec<String>>,
+    /// Shape of an `.npy` array with other than two dimensions, which is read as one column
+    pub shape: Option<Vec<usize>>,
+}
+
+impl<T> Data<T> {
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Data<U> {
+        Data {
+            columns: self.columns.into_iter().map(|c| c.into_iter().map(&mut f).collect()).collect(),
+            header: self.header,
+            shape: self.shape,
+        }
+    }
+
+    fn rows(&self) -> usize {
+        self.columns.first().map_or(0, Vec::len)
+    }
+
+    /// Iterates over the elements in row-major order.
+    fn row_major(&self) -> impl Iterator<Item = &T> {
+        (0..self.rows()).flat_map(move |r| self.columns.iter().map(move |c| &c[r]))
+    }
+}
+
+/// Reads `path`, returning its data and, for `.npy` files, the precision it was stored in. Raw
+/// binary is read as `raw_precision`.
+pub fn read(path: &Path, format: Format, raw_precision: Precision) -> Result<(Data<f64>, Option<Precision>), String> {
+    let bytes = fs::read(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let context = |e: String| format!("{}: {}", path.display(), e);
+    match format {
+        Format::Csv => {
+            let text = String::from_utf8(bytes).map_err(|_| context("not valid UTF-8".into()))?;
+            Ok((read_csv(&text).map_err(context)?, None))
+        }
+        Format::Npy => read_npy(&bytes).map(|(d, p)| (d, Some(p))).map_err(context),
+        Format::Raw => {
+            let values = decode(&bytes, raw_precision).map_err(context)?;
+            Ok((Data { columns: vec![values], header: None, shape: None }, None))
+        }
+    }
+}
+
+/// Writes `data` to `path`, storing binary formats in `precision`.
+pub fn write(path: &Path, format: Format, data: &Data<f64>, precision: Precision) -> Result<(), String> {
+    let file = fs::File::create(path).map_err(|e| format!("cannot create {}: {}", path.display(), e))?;
+    let mut writer = BufWriter::new(file);
+    let result = match format {
+        Format::Csv => write_csv(&mut writer, data, precision),
+        Format::Npy => write_npy(&mut writer, data, precision),
+        Format::Raw => write_values(&mut writer, data.row_major().copied(), precision),
+    };
+    result.and_then(|()| writer.flush()).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+fn read_csv(text: &str) -> Result<Data<f64>, String> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty()).peekable();
+    let parse = |field: &str| -> Option<f64> {
+        let field = field.trim();
+        if field.is_empty() { Some(f64::NAN) } else { field.parse().ok() }
+    };
+    let header = match lines.peek() {
+        Some(first) if first.split(',').any(|f| parse(f).is_none()) => {
+            Some(first.split(',').map(|f| f.trim().to_string()).collect::<Vec<_>>())
+        }
+        _ => None,
+    };
+    if header.is_some() {
+        lines.next();
+    }
+
+    let mut columns: Vec<Vec<f64>> = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let fields: Vec<&str> = line.split(',').collect();
+        if columns.is_empty() {
+            columns = vec![Vec::new(); fields.len()];
+        }
+        if fields.len() != columns.len() {
+            return Err(format!("row {} has {} fields, expected {}", i + 1, fields.len(), columns.len()));
+        }
+        for (column, field) in columns.iter_mut().zip(fields) {
+            column.push(parse(field).ok_or_else(|| format!("row {}: `{}` is not a number", i + 1, field.trim()))?);
+        }
+    }
+    Ok(Data { columns, header, shape: None })
+}
+
+/// Writes `data` as CSV, formatting each value as the shortest decimal that reads back as the
+/// same value in `precision`.
+pub fn write_csv(mut writer: impl Write, data: &Data<f64>, precision: Precision) -> io::Result<()> {
+    if let Some(header) = &data.header {
+        writeln!(writer, "{}", header.join(","))?;
+    }
+    for r in 0..data.rows() {
+        for (c, column) in data.columns.iter().enumerate() {
+            if c > 0 {
+                write!(writer, ",")?;
+            }
+            match precision {
+                Precision::F32 => write!(writer, "{}", column[r] as f32)?,
+                Precision::F64 => write!(writer, "{}", column[r])?,
+            }
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+fn decode(bytes: &[u8], precision: Precision) -> Result<Vec<f64>, String> {
+    match precision {
+        Precision::F32 => {
+            let chunks = bytes.chunks_exact(4);
+            if !chunks.remainder().is_empty() {
+                return Err(format!("{} bytes is not a whole number of f32 values", bytes.len()));
+            }
+            Ok(chunks.map(|b| f32::from_le_bytes(b.try_into().unwrap()) as f64).collect())
+        }
+        Precision::F64 => {
+            let chunks = bytes.chunks_exact(8);
+            if !chunks.remainder().is_empty() {
+                return Err(format!("{} bytes is not a whole number of f64 values", bytes.len()));
+            }
+            Ok(chunks.map(|b| f64::from_le_bytes(b.try_into().unwrap())).collect())
+        }
+    }
+}
+
+fn write_values(mut writer: impl Write, values: impl Iterator<Item = f64>, precision: Precision) -> io::Result<()> {
+    for x in values {
+        match precision {
+            Precision::F32 => writer.write_all(&(x as f32).to_le_bytes())?,
+            Precision::F64 => writer.write_all(&x.to_le_bytes())?,
+        }
+    }
+    Ok(())
+}
+
+fn read_npy(bytes: &[u8]) -> Result<(Data<f64>, Precision), String> {
+    let (shape, fortran_order, values, precision) = match NpyArray::<f64>::from_bytes(bytes) {
+        Ok(array) => (array.shape().to_vec(), array.fortran_order(), array.into_data(), Precision::F64),
+        Err(NpyError::Dtype { .. }) => {
+            let array = NpyArray::<f32>::from_bytes(bytes).map_err(|e| e.to_string())?;
+            let values = array.data().iter().map(|&x| x as f64).collect();
+            (array.shape().to_vec(), array.fortran_order(), values, Precision::F32)
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let data = match shape[..] {
+        [rows, cols] => {
+            let at = |r: usize, c: usize| if fortran_order { values[c * rows + r] } else { values[r * cols + c] };
+            let columns = (0..cols).map(|c| (0..rows).map(|r| at(r, c)).collect()).collect();
+            Data { columns, header: None, shape: None }
+        }
+        [_] => Data { columns: vec![values], header: None, shape: None },
+        _ if fortran_order => return Err("Fortran-ordered arrays are only supported in one or two dimensions".into()),
+        _ => Data { columns: vec![values], header: None, shape: Some(shape) },
+    };
+    Ok((data, precision))
+}
+
+fn write_npy(mut writer: impl Write, data: &Data<f64>, precision: Precision) -> io::Result<()> {
+    let shape = match (&data.shape, data.columns.len()) {
+        (Some(shape), _) => shape.clone(),
+        (None, 1) => vec![data.rows()],
+        (None, cols) => vec![data.rows(), cols],
+    };
+    let values = data.row_major().copied();
+    let bytes = match precision {
+        Precision::F32 => NpyArray::new(values.map(|x| x as f32).collect(), shape, false).map(|a| a.to_bytes()),
+        Precision::F64 => NpyArray::new(values.collect(), shape, false).map(|a| a.to_bytes()),
+    };
+    writer.write_all(&bytes.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?)
+}
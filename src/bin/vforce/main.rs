@@ -0,0 +1,281 @@
+//! Command-line interface applying the vforce array functions to data files.
+//!
+//! ```text
+//! vforce apply --op exp --op log1p input.npy -o out.npy
+//! ```
+mod formats;
+
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use vforce::io::{Precision, BLOCK_LEN};
+use vforce::op::Op;
+use vforce::AccelerateFloat;
+
+use formats::{Data, Format};
+
+const USAGE: &str = "\
+Usage:
+  vforce apply --op <OP> [--op <OP>...] [options] <INPUT>
+  vforce ops
+
+Applies each operation in turn to every column of INPUT. The format of INPUT and of the output
+is taken from the file extension: .csv, .npy, or raw little-endian binary for anything else.
+
+Options:
+  --op <OP>           Unary operation to apply, such as exp or log1p; see `vforce ops`
+  -o, --output <PATH> Output file; results are written to stdout as CSV if omitted
+  --f32, --f64        Precision to compute and write in; defaults to the precision of an .npy
+                      input, and to f64 otherwise. Also sets the element type of raw binary input
+  --threads <N>       Number of threads to split each column over, defaulting to 1
+  -h, --help          Print this message";
+
+struct Apply {
+    ops: Vec<Op>,
+    input: PathBuf,
+    output: Option<PathBuf>,
+    precision: Option<Precision>,
+    threads: NonZeroUsize,
+}
+
+enum Command {
+    Apply(Apply),
+    Ops,
+    Help,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("apply") => {}
+        Some("ops") => return Ok(Command::Ops),
+        Some("-h" | "--help") | None => return Ok(Command::Help),
+        Some(other) => return Err(format!("unknown command `{}`", other)),
+    }
+
+    let mut ops = Vec::new();
+    let mut input = None;
+    let mut output = None;
+    let mut precision = None;
+    let mut threads = NonZeroUsize::MIN;
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("`{}` requires a value", name));
+        match arg.as_str() {
+            "--op" => {
+                let name = value("--op")?;
+                let op = Op::from_str(&name).map_err(|_| format!("unknown operation `{}`", name))?;
+                if op.arity() != 1 {
+                    return Err(format!("`{}` takes {} inputs, but only unary operations can be applied", op, op.arity()));
+                }
+                ops.push(op);
+            }
+            "-o" | "--output" => output = Some(PathBuf::from(value(&arg)?)),
+            "--f32" => precision = Some(Precision::F32),
+            "--f64" => precision = Some(Precision::F64),
+            "--threads" => {
+                let n = value("--threads")?;
+                threads = n.parse().map_err(|_| format!("`--threads` must be a positive integer, got `{}`", n))?;
+            }
+            "-h" | "--help" => return Ok(Command::Help),
+            flag if flag.starts_with('-') && flag != "-" => return Err(format!("unknown option `{}`", flag)),
+            path => {
+                if input.replace(PathBuf::from(path)).is_some() {
+                    return Err("more than one input file given".into());
+                }
+            }
+        }
+    }
+
+    if ops.is_empty() {
+        return Err("no operations given; pass at least one `--op`".into());
+    }
+    let input = input.ok_or("no input file given")?;
+    Ok(Command::Apply(Apply { ops, input, output, precision, threads }))
+}
+
+/// Number of elements copied out of a column at a time, so that operations can be applied to it
+/// in place
+const TILE: usize = 256;
+
+/// Applies `op` to `column` in place. Columns of elementwise operations are processed a tile at a
+/// time, and split over up to `threads` threads if each gets at least `BLOCK_LEN` elements.
+fn apply_op<AF: AccelerateFloat + Send + Sync>(op: Op, column: &mut [AF], threads: NonZeroUsize) -> Result<(), String> {
+    if !op.is_elementwise() {
+        let input = column.to_vec();
+        return op.apply(column, &[&input]).map_err(|e| e.to_string());
+    }
+    let parts = threads.get().min(column.len() / BLOCK_LEN).max(1);
+    if parts == 1 {
+        return apply_tiled(op, column);
+    }
+    let part_len = column.len().div_ceil(parts);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = column.chunks_mut(part_len)
+            .map(|part| scope.spawn(move || apply_tiled(op, part)))
+            .collect();
+        handles.into_iter().try_for_each(|handle| handle.join().map_err(|_| "a worker thread panicked".to_string())?)
+    })
+}
+
+/// Applies the elementwise `op` to `column` in place, a tile at a time.
+fn apply_tiled<AF: AccelerateFloat>(op: Op, column: &mut [AF]) -> Result<(), String> {
+    let mut input = [AF::ZERO; TILE];
+    for tile in column.chunks_mut(TILE) {
+        let input = &mut input[..tile.len()];
+        input.copy_from_slice(tile);
+        op.apply(tile, &[input]).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn run_in<AF: AccelerateFloat + Send + Sync>(
+    apply: &Apply, data: Data<f64>, precision: Precision
+) -> Result<(), String> {
+    let mut data = data.map(AF::from_f64);
+    for &op in &apply.ops {
+        for column in data.columns.iter_mut() {
+            apply_op(op, column, apply.threads)?;
+        }
+    }
+    let data = data.map(AF::to_f64);
+    match &apply.output {
+        Some(path) => formats::write(path, Format::of(path), &data, precision),
+        None => formats::write_csv(std::io::stdout().lock(), &data, precision).map_err(|e| e.to_string()),
+    }
+}
+
+fn run(apply: Apply) -> Result<(), String> {
+    let format = Format::of(&apply.input);
+    let (data, read_precision) = formats::read(&apply.input, format, apply.precision.unwrap_or(Precision::F64))?;
+    match apply.precision.or(read_precision).unwrap_or(Precision::F64) {
+        Precision::F32 => run_in::<f32>(&apply, data, Precision::F32),
+        Precision::F64 => run_in::<f64>(&apply, data, Precision::F64),
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Ops => {
+            for op in Op::ALL.iter().filter(|op| op.arity() == 1) {
+                println!("{}", op);
+            }
+        }
+        Command::Apply(apply) => {
+            if let Err(message) = run(apply) {
+                eprintln!("error: {}", message);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    /// Returns a path in the temporary directory unique to this process and `name`.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("vforce-cli-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let args = ["apply", "--op", "exp", "--op", "log1p", "--f32", "--threads", "4", "in.csv", "-o", "out.npy"];
+        let Ok(Command::Apply(apply)) = parse(&args) else {
+            panic!("valid arguments were rejected");
+        };
+        assert_eq!(apply.ops, [Op::Exp, Op::Log1p]);
+        assert_eq!(apply.input, Path::new("in.csv"));
+        assert_eq!(apply.output.as_deref(), Some(Path::new("out.npy")));
+        assert_eq!(apply.precision, Some(Precision::F32));
+        assert_eq!(apply.threads.get(), 4);
+        assert!(matches!(parse(&[]), Ok(Command::Help)));
+        assert!(matches!(parse(&["ops"]), Ok(Command::Ops)));
+
+        let error = |args: &[&str]| parse(args).err().expect("invalid arguments were accepted");
+        assert_eq!(error(&["frobnicate"]), "unknown command `frobnicate`");
+        assert_eq!(error(&["apply", "--op", "nope", "in.csv"]), "unknown operation `nope`");
+        assert!(error(&["apply", "--op", "pow", "in.csv"]).contains("only unary operations"));
+        assert_eq!(error(&["apply", "in.csv", "--op"]), "`--op` requires a value");
+        assert!(error(&["apply", "--op", "exp", "--threads", "0", "in.csv"]).contains("positive integer"));
+        assert!(error(&["apply", "--op", "exp", "--frob", "in.csv"]).contains("unknown option `--frob`"));
+        assert!(error(&["apply", "in.csv"]).starts_with("no operations given"));
+        assert_eq!(error(&["apply", "--op", "exp"]), "no input file given");
+        assert_eq!(error(&["apply", "--op", "exp", "a.csv", "b.csv"]), "more than one input file given");
+    }
+
+    #[test]
+    fn test_apply_op() {
+        let input: Vec<f64> = (0..3 * BLOCK_LEN + 5).map(|i| i as f64 / 1000.0).collect();
+        let mut expected = vec![0.0; input.len()];
+        Op::Log1p.apply(&mut expected, &[&input]).unwrap();
+        for threads in [1, 4] {
+            let mut column = input.clone();
+            apply_op(Op::Log1p, &mut column, NonZeroUsize::new(threads).unwrap()).unwrap();
+            assert_eq!(column, expected);
+        }
+
+        let mut column = vec![0.0f32, 0.0];
+        apply_op(Op::Softmax, &mut column, NonZeroUsize::new(4).unwrap()).unwrap();
+        assert_eq!(column, [0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let input = temp_path("in.csv");
+        let output = temp_path("out.csv");
+        std::fs::write(&input, "x, y\n0,1\n,4\n").unwrap();
+        let (mut data, precision) = formats::read(&input, Format::Csv, Precision::F64).unwrap();
+        assert_eq!(data.header.as_deref(), Some(&["x".to_string(), "y".to_string()][..]));
+        assert!(precision.is_none());
+        for column in data.columns.iter_mut() {
+            apply_op(Op::Sqrt, column, NonZeroUsize::MIN).unwrap();
+        }
+        formats::write(&output, Format::Csv, &data, Precision::F64).unwrap();
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "x,y\n0,1\nNaN,2\n");
+
+        // a first line of numbers, including NaN and infinity, is data rather than a header
+        std::fs::write(&input, "nan,inf\n1,2\n").unwrap();
+        let (data, _) = formats::read(&input, Format::Csv, Precision::F64).unwrap();
+        assert!(data.header.is_none());
+        assert_eq!(data.columns[1], [f64::INFINITY, 2.0]);
+        std::fs::remove_file(input).unwrap();
+        std::fs::remove_file(output).unwrap();
+    }
+
+    #[test]
+    fn test_npy_and_raw_roundtrip() {
+        let npy = temp_path("data.npy");
+        let raw = temp_path("data.bin");
+        let data = formats::Data { columns: vec![vec![0.0, 1.0], vec![2.0, 3.0]], header: None, shape: None };
+        formats::write(&npy, Format::Npy, &data, Precision::F32).unwrap();
+        let (mut read, precision) = formats::read(&npy, Format::Npy, Precision::F64).unwrap();
+        assert_eq!(precision, Some(Precision::F32));
+        assert_eq!(read.columns, data.columns);
+
+        for column in read.columns.iter_mut() {
+            apply_op(Op::Exp2, column, NonZeroUsize::MIN).unwrap();
+        }
+        formats::write(&raw, Format::Raw, &read, Precision::F64).unwrap();
+        let (read, _) = formats::read(&raw, Format::of(&raw), Precision::F64).unwrap();
+        // raw binary holds no shape, so the values are read back in row-major order as one column
+        assert_eq!(read.columns, [[1.0, 4.0, 2.0, 8.0]]);
+        std::fs::remove_file(npy).unwrap();
+        std::fs::remove_file(raw).unwrap();
+    }
+}
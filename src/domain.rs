@@ -0,0 +1,143 @@
+//! Groups together domain checks for the functions which return NaN outside of their domain,
+//! and the checked variants of those functions built on them
+//!
+//! [`Domain`] covers the unary functions, and [`BinaryDomain`] the binary functions, whose
+//! domains depend on both inputs together.
+//!
+//! The checked variants validate the whole input before computing anything, so on error `out`
+//! is left untouched. NaN inputs are not reported, since they are passed through rather than
+//! produced; use [`is_nan_array`](crate::mask::is_nan_array) to find them.
+//!
+//! ```rust
+//! use vforce::AccelerateError;
+//! use vforce::domain::Domain;
+//! use vforce::exponential::log_array_checked;
+//! let input: Vec<f64> = vec![1.0, -2.0, 3.0, -4.0];
+//! let mut out = vec![0.0f64; 4];
+//!
+//! let result = log_array_checked(&mut out, &input);
+//! assert!(matches!(result, Err(AccelerateError::DomainError { index: 1, .. })));
+//!
+//! let violations: Vec<usize> = Domain::NonNegative.violations(&input).collect();
+//! assert_eq!(violations, vec![1, 3]);
+//! ```
+use super::*;
+use crate::mask::MaskElement;
+
+/// The set of inputs for which a function is defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Domain {
+    /// x >= 0, the domain of the square root and logarithm functions
+    NonNegative,
+    /// x >= -1, the domain of log1p
+    AtLeastMinusOne,
+    /// -1 <= x <= 1, the domain of the inverse sine, cosine and hyperbolic tangent
+    UnitInterval,
+    /// x >= 1, the domain of the inverse hyperbolic cosine
+    AtLeastOne,
+}
+
+impl Domain {
+    /// Returns true if `x` lies in the domain, or is NaN.
+    pub fn contains<AF: AccelerateFloat>(self, x: AF) -> bool {
+        if x.is_nan() {
+            return true;
+        }
+        match self {
+            Self::NonNegative => x >= AF::ZERO,
+            Self::AtLeastMinusOne => x >= -AF::ONE,
+            Self::UnitInterval => x.abs() <= AF::ONE,
+            Self::AtLeastOne => x >= AF::ONE,
+        }
+    }
+
+    /// Returns `AccelerateError::DomainError` for the first element of `input` outside the domain.
+    pub fn check<AF: AccelerateFloat>(self, input: &[AF]) -> Result<(), AccelerateError> {
+        match input.iter().position(|&x| !self.contains(x)) {
+            Some(index) => Err(AccelerateError::DomainError { index, value: input[index].to_f64() }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the indices of all elements of `input` outside the domain, in ascending order.
+    pub fn violations<AF: AccelerateFloat>(self, input: &[AF]) -> impl Iterator<Item = usize> + '_ {
+        input.iter().enumerate().filter(move |&(_, &x)| !self.contains(x)).map(|(i, _)| i)
+    }
+
+    /// Writes a mask to `out` that is set for each element of `input` outside the domain.
+    pub fn violations_mask<AF: AccelerateFloat, M: MaskElement>(
+        self, out: &mut [M], input: &[AF]
+    ) -> Result<(), AccelerateError> {
+        check_mask_len!(input, out)?;
+        M::write_lanes(out, input.iter().map(|&x| !self.contains(x)));
+        Ok(())
+    }
+}
+
+/// The set of pairs of inputs for which a binary function is defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryDomain {
+    /// x >= 0, or y an integer, or either infinite, the domain of pow(x, y) over the reals
+    RealPower,
+    /// x finite and y != 0, the domain of fmod(x, y) and remainder(x, y)
+    NonZeroDivisor,
+    /// (y, x) != (0, 0), where atan2(y, x) has no limit, although it returns +/-0 or +/-pi there
+    NotBothZero,
+}
+
+impl BinaryDomain {
+    /// Returns true if the pair `(a, b)`, in the order the function takes them, lies in the
+    /// domain, or either is NaN.
+    pub fn contains<AF: AccelerateFloat>(self, a: AF, b: AF) -> bool {
+        if a.is_nan() || b.is_nan() {
+            return true;
+        }
+        match self {
+            Self::RealPower => !a.is_finite() || !b.is_finite() || a >= AF::ZERO || b.to_f64() % 1.0 == 0.0,
+            Self::NonZeroDivisor => a.is_finite() && b != AF::ZERO,
+            Self::NotBothZero => a != AF::ZERO || b != AF::ZERO,
+        }
+    }
+
+    /// Returns the element of the pair `(a, b)` outside the domain which puts it outside: the base
+    /// for pow, the divisor if it is zero and the numerator otherwise for fmod and remainder, and
+    /// y for atan2.
+    fn culprit<AF: AccelerateFloat>(self, a: AF, b: AF) -> AF {
+        match self {
+            Self::NonZeroDivisor if b == AF::ZERO => b,
+            _ => a,
+        }
+    }
+
+    /// Returns `AccelerateError::DomainError` for the first pair of elements of `first` and
+    /// `second` outside the domain, with the value of the element which puts it outside.
+    pub fn check<AF: AccelerateFloat>(self, first: &[AF], second: &[AF]) -> Result<(), AccelerateError> {
+        check_lengths!(first, second)?;
+        match first.iter().zip(second).position(|(&a, &b)| !self.contains(a, b)) {
+            Some(index) => {
+                let value = self.culprit(first[index], second[index]).to_f64();
+                Err(AccelerateError::DomainError { index, value })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the indices of all pairs of elements of `first` and `second` outside the domain, in
+    /// ascending order, up to the length of the shorter.
+    pub fn violations<'a, AF: AccelerateFloat>(
+        self, first: &'a [AF], second: &'a [AF]
+    ) -> impl Iterator<Item = usize> + 'a {
+        first.iter().zip(second).enumerate().filter(move |&(_, (&a, &b))| !self.contains(a, b)).map(|(i, _)| i)
+    }
+
+    /// Writes a mask to `out` that is set for each pair of elements of `first` and `second`
+    /// outside the domain.
+    pub fn violations_mask<AF: AccelerateFloat, M: MaskElement>(
+        self, out: &mut [M], first: &[AF], second: &[AF]
+    ) -> Result<(), AccelerateError> {
+        check_lengths!(first, second)?;
+        check_mask_len!(first, out)?;
+        M::write_lanes(out, first.iter().zip(second).map(|(&a, &b)| !self.contains(a, b)));
+        Ok(())
+    }
+}
@@ -6,6 +6,9 @@ unary_vforce_op!(
     exp_array,
     /// Computes e^x for each element, overwriting `input` with the results.
     exp_array_in_place,
+    /// Computes e^x for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    exp_array_masked,
     accelerate_exp, input);
 
 unary_vforce_op!(
@@ -13,6 +16,9 @@ unary_vforce_op!(
     exp2_array,
     /// Computes 2^x for each element, overwriting `input` with the results.
     exp2_array_in_place,
+    /// Computes 2^x for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    exp2_array_masked,
     accelerate_exp2, input);
 
 unary_vforce_op!(
@@ -20,6 +26,9 @@ unary_vforce_op!(
     expm1_array,
     /// Computes e^x - 1 for each element, overwriting `input` with the results.
     expm1_array_in_place,
+    /// Computes e^x - 1 for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    expm1_array_masked,
     accelerate_expm1, input);
 
 unary_vforce_op!(
@@ -27,32 +36,415 @@ unary_vforce_op!(
     log_array,
     /// Computes the natural logarithm of each element, overwriting `input` with the results.
     log_array_in_place,
+    /// Computes the natural logarithm of each element, writing the results to `out` where `mask`
+    /// is set and leaving the other elements of `out` unchanged.
+    log_array_masked,
     accelerate_log, input);
 
+checked_unary_op!(
+    /// Computes the natural logarithm of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without writing anything.
+    log_array_checked,
+    /// Computes the natural logarithm of each element, overwriting `input` with the results, or
+    /// returns `AccelerateError::DomainError` for the first element with x < 0 without modifying
+    /// `input`.
+    log_array_in_place_checked,
+    log_array, log_array_in_place, crate::domain::Domain::NonNegative);
+
 unary_vforce_op!(
     /// Computes ln(1 + x) for each element, writing the results to `out`.
     log1p_array,
     /// Computes ln(1 + x) for each element, overwriting `input` with the results.
     log1p_array_in_place,
+    /// Computes ln(1 + x) for each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    log1p_array_masked,
     accelerate_log1p, input);
 
+checked_unary_op!(
+    /// Computes ln(1+x) for each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with x < -1 without writing anything.
+    log1p_array_checked,
+    /// Computes ln(1+x) for each element, overwriting `input` with the results, or returns
+    /// `AccelerateError::DomainError` for the first element with x < -1 without modifying `input`.
+    log1p_array_in_place_checked,
+    log1p_array, log1p_array_in_place, crate::domain::Domain::AtLeastMinusOne);
+
 unary_vforce_op!(
     /// Computes the base-2 logarithm of each element, writing the results to `out`.
     log2_array,
     /// Computes the base-2 logarithm of each element, overwriting `input` with the results.
     log2_array_in_place,
+    /// Computes the base-2 logarithm of each element, writing the results to `out` where `mask`
+    /// is set and leaving the other elements of `out` unchanged.
+    log2_array_masked,
     accelerate_log2, input);
 
+checked_unary_op!(
+    /// Computes the base 2 logarithm of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without writing anything.
+    log2_array_checked,
+    /// Computes the base 2 logarithm of each element, overwriting `input` with the results, or
+    /// returns `AccelerateError::DomainError` for the first element with x < 0 without modifying
+    /// `input`.
+    log2_array_in_place_checked,
+    log2_array, log2_array_in_place, crate::domain::Domain::NonNegative);
+
 unary_vforce_op!(
     /// Computes the base-10 logarithm of each element, writing the results to `out`.
     log10_array,
     /// Computes the base-10 logarithm of each element, overwriting `input` with the results.
     log10_array_in_place,
+    /// Computes the base-10 logarithm of each element, writing the results to `out` where `mask`
+    /// is set and leaving the other elements of `out` unchanged.
+    log10_array_masked,
     accelerate_log10, input);
 
+checked_unary_op!(
+    /// Computes the base 10 logarithm of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with x < 0 without writing anything.
+    log10_array_checked,
+    /// Computes the base 10 logarithm of each element, overwriting `input` with the results, or
+    /// returns `AccelerateError::DomainError` for the first element with x < 0 without modifying
+    /// `input`.
+    log10_array_in_place_checked,
+    log10_array, log10_array_in_place, crate::domain::Domain::NonNegative);
+
 unary_vforce_op!(
     /// Extracts the exponent of each element as a signed integral value, writing the results to `out`.
     logb_array,
     /// Extracts the exponent of each element as a signed integral value, overwriting `input` with the results.
     logb_array_in_place,
+    /// Extracts the exponent of each element as a signed integral value, writing the results to
+    /// `out` where `mask` is set and leaving the other elements of `out` unchanged.
+    logb_array_masked,
     accelerate_logb, input);
+
+/// Computes 10^x for each element of `input` with the pow kernel, writing the results to `out`.
+/// `out` and `input` may point to the same memory.
+///
+/// # Safety
+/// `out` and `input` must both be valid for `count` elements, and `count` must not exceed `TILE`
+unsafe fn exp10_tile<AF: AccelerateFloat>(out: *mut AF, input: *const AF, count: i32) {
+    let tens = [AF::from_f64(10.0); TILE];
+    unsafe { AF::accelerate_pow(out, tens.as_ptr(), input, &count); }
+}
+
+/// Computes 2^x for each element of `input`, writing the results to `out`.
+///
+/// # Safety
+/// `out` and `input` must both be valid for `count` elements
+unsafe fn exp2_tile<AF: AccelerateFloat>(out: *mut AF, input: *const AF, count: i32) {
+    unsafe { AF::accelerate_exp2(out, input, &count); }
+}
+
+/// Computes b^x - 1 for each element of `values`, which holds x on entry, as expm1(x * ln(b))
+/// where |x| < 1 and as b^x - 1 elsewhere. The former loses accuracy as x grows, since the
+/// rounding error in x * ln(b) is proportional to x, and the latter loses accuracy to
+/// cancellation for small x.
+fn exp_base_m1_into<AF: AccelerateFloat>(
+    values: &mut [AF], ln_base: AF, exp_base: unsafe fn(*mut AF, *const AF, i32)
+) {
+    let mut x_buf = [AF::ZERO; TILE];
+    let mut pow_buf = [AF::ZERO; TILE];
+    for tile in values.chunks_mut(TILE) {
+        let count = tile.len() as i32;
+        let x = &mut x_buf[..tile.len()];
+        let pow = &mut pow_buf[..tile.len()];
+        x.copy_from_slice(tile);
+        unsafe { exp_base(pow.as_mut_ptr(), x.as_ptr(), count); }
+        for t in tile.iter_mut() {
+            *t = *t * ln_base;
+        }
+        unsafe { AF::accelerate_expm1(tile.as_mut_ptr(), tile.as_ptr(), &count); }
+        for (t, (&x, &p)) in tile.iter_mut().zip(x.iter().zip(pow.iter())) {
+            if x.abs() >= AF::ONE {
+                *t = p - AF::ONE;
+            }
+        }
+    }
+}
+
+/// Computes 10^x for each element, writing the results to `out`.
+pub fn exp10_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    for (out_chunk, in_chunk) in out.chunks_mut(TILE).zip(input.chunks(TILE)) {
+        unsafe { exp10_tile(out_chunk.as_mut_ptr(), in_chunk.as_ptr(), in_chunk.len() as i32); }
+    }
+    Ok(())
+}
+
+/// Computes 10^x for each element, overwriting `input` with the results.
+pub fn exp10_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    for chunk in input.chunks_mut(TILE) {
+        unsafe { exp10_tile(chunk.as_mut_ptr(), chunk.as_ptr(), chunk.len() as i32); }
+    }
+}
+
+/// Computes 2^x - 1 for each element, accurately for x near zero, writing the results to `out`.
+pub fn exp2m1_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    exp_base_m1_into(out, AF::from_f64(core::f64::consts::LN_2), exp2_tile);
+    Ok(())
+}
+
+/// Computes 2^x - 1 for each element, accurately for x near zero, overwriting `input` with the
+/// results.
+pub fn exp2m1_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    exp_base_m1_into(input, AF::from_f64(core::f64::consts::LN_2), exp2_tile);
+}
+
+/// Computes 10^x - 1 for each element, accurately for x near zero, writing the results to `out`.
+pub fn exp10m1_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    exp_base_m1_into(out, AF::from_f64(core::f64::consts::LN_10), exp10_tile);
+    Ok(())
+}
+
+/// Computes 10^x - 1 for each element, accurately for x near zero, overwriting `input` with the
+/// results.
+pub fn exp10m1_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    exp_base_m1_into(input, AF::from_f64(core::f64::consts::LN_10), exp10_tile);
+}
+
+/// Computes the base-`base` logarithm of each element, writing the results to `out`.
+///
+/// Bases 2 and 10 use the dedicated kernels directly; other bases are computed as
+/// log2(x) / log2(base).
+pub fn logn_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], base: AF
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    if base == AF::from_f64(10.0) {
+        return log10_array(out, input);
+    }
+    log2_array(out, input)?;
+    if base != AF::from_f64(2.0) {
+        let log2_base = scalar_op(AF::accelerate_log2, base);
+        for o in out.iter_mut() {
+            *o = *o / log2_base;
+        }
+    }
+    Ok(())
+}
+
+/// Computes the base-`base` logarithm of each element, overwriting `input` with the results.
+///
+/// Bases 2 and 10 use the dedicated kernels directly; other bases are computed as
+/// log2(x) / log2(base).
+pub fn logn_array_in_place<AF: AccelerateFloat>(input: &mut [AF], base: AF) {
+    if base == AF::from_f64(10.0) {
+        return log10_array_in_place(input);
+    }
+    log2_array_in_place(input);
+    if base != AF::from_f64(2.0) {
+        let log2_base = scalar_op(AF::accelerate_log2, base);
+        for o in input.iter_mut() {
+            *o = *o / log2_base;
+        }
+    }
+}
+
+// ── Softmax and log-sum-exp ──
+
+/// Finds the value subtracted from every element before exponentiation: the maximum element,
+/// or zero if the maximum is infinite (or the input is empty), so that the shift itself never
+/// introduces NaNs.
+fn shift_of<AF: AccelerateFloat>(input: &[AF]) -> AF {
+    let max = input.iter().fold(AF::NEG_INFINITY, |m, &x| if x > m { x } else { m });
+    if max.is_finite() { max } else { AF::ZERO }
+}
+
+/// Computes the sum of e^(x - shift) over `input`, staging each tile of shifted values in a
+/// stack buffer so that no additional memory is required.
+fn shifted_exp_sum<AF: AccelerateFloat>(input: &[AF], shift: AF) -> AF {
+    let mut buf = [AF::ZERO; TILE];
+    let mut sum = AF::ZERO;
+    for in_chunk in input.chunks(TILE) {
+        let tile = &mut buf[..in_chunk.len()];
+        for (t, &x) in tile.iter_mut().zip(in_chunk) {
+            *t = x - shift;
+        }
+        let count = tile.len() as i32;
+        unsafe { AF::accelerate_exp(tile.as_mut_ptr(), tile.as_ptr(), &count); }
+        sum = tile.iter().fold(sum, |s, &t| s + t);
+    }
+    sum
+}
+
+/// Writes e^(x - max) into `out` tile by tile, accumulating the sum while each tile is still in
+/// cache, then normalizes. `out` must already hold the input values.
+fn softmax_into<AF: AccelerateFloat>(out: &mut [AF], shift: AF) {
+    let mut sum = AF::ZERO;
+    for tile in out.chunks_mut(TILE) {
+        for t in tile.iter_mut() {
+            *t = *t - shift;
+        }
+        let count = tile.len() as i32;
+        unsafe { AF::accelerate_exp(tile.as_mut_ptr(), tile.as_ptr(), &count); }
+        sum = tile.iter().fold(sum, |s, &t| s + t);
+    }
+    for o in out.iter_mut() {
+        *o = *o / sum;
+    }
+}
+
+/// Writes x - logsumexp(x) into `out`, which must already hold the contents of the input.
+fn log_softmax_into<AF: AccelerateFloat>(out: &mut [AF]) {
+    let shift = shift_of(out);
+    let lse = shift + scalar_op(AF::accelerate_log, shifted_exp_sum(out, shift));
+    for o in out.iter_mut() {
+        *o = *o - lse;
+    }
+}
+
+/// Computes the log of the sum of the exponentials of the elements, ln(Σ e^x), without
+/// overflowing for large inputs.
+///
+/// The maximum element is subtracted before exponentiation and added back afterwards. An empty
+/// input returns -inf.
+///
+/// # Example
+///
+/// ```rust
+/// use vforce::exponential::logsumexp;
+/// let input: Vec<f64> = vec![1000.0, 1000.0];
+/// let lse = logsumexp(&input);
+/// assert!((lse - (1000.0 + 2.0f64.ln())).abs() < 1e-9);
+/// ```
+pub fn logsumexp<AF: AccelerateFloat>(input: &[AF]) -> AF {
+    let shift = shift_of(input);
+    shift + scalar_op(AF::accelerate_log, shifted_exp_sum(input, shift))
+}
+
+/// Computes the softmax e^x / Σ e^x of the elements, writing the results to `out`.
+///
+/// The maximum element is subtracted before exponentiation, so large inputs do not overflow.
+pub fn softmax_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    softmax_into(out, shift_of(input));
+    Ok(())
+}
+
+/// Computes the softmax e^x / Σ e^x of the elements, overwriting `input` with the results.
+///
+/// The maximum element is subtracted before exponentiation, so large inputs do not overflow.
+pub fn softmax_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    let shift = shift_of(input);
+    softmax_into(input, shift);
+}
+
+/// Computes the log-softmax x - ln(Σ e^x) of the elements, writing the results to `out`.
+pub fn log_softmax_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    log_softmax_into(out);
+    Ok(())
+}
+
+/// Computes the log-softmax x - ln(Σ e^x) of the elements, overwriting `input` with the results.
+pub fn log_softmax_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    log_softmax_into(input);
+}
+
+/// Returns the number of elements of a row-major `rows` x `cols` buffer, or
+/// `AccelerateError::LengthMismatch` for `input`, with the saturated length `usize::MAX` expected,
+/// if that overflows.
+fn matrix_len(rows: usize, cols: usize, input_len: usize) -> Result<usize, AccelerateError> {
+    rows.checked_mul(cols).ok_or(AccelerateError::LengthMismatch {
+        argument: "input", reference: "rows * cols", expected: usize::MAX, got: input_len
+    })
+}
+
+/// Computes the log-sum-exp of each row of a row-major `rows` x `cols` buffer, writing one
+/// result per row to `out`.
+pub fn logsumexp_rows<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], rows: usize, cols: usize
+) -> Result<(), AccelerateError> {
+    check_lengths(("rows * cols", matrix_len(rows, cols, input.len())?), &[("input", input.len())])?;
+    check_lengths(("rows", rows), &[("out", out.len())])?;
+    if cols == 0 {
+        out.fill(AF::NEG_INFINITY);
+        return Ok(());
+    }
+    for (o, row) in out.iter_mut().zip(input.chunks_exact(cols)) {
+        *o = logsumexp(row);
+    }
+    Ok(())
+}
+
+/// Computes the softmax of each row of a row-major `rows` x `cols` buffer, writing the results
+/// to `out`.
+pub fn softmax_rows<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], rows: usize, cols: usize
+) -> Result<(), AccelerateError> {
+    check_lengths(("rows * cols", matrix_len(rows, cols, input.len())?), &[("input", input.len()), ("out", out.len())])?;
+    if cols == 0 {
+        return Ok(());
+    }
+    for (out_row, in_row) in out.chunks_exact_mut(cols).zip(input.chunks_exact(cols)) {
+        out_row.copy_from_slice(in_row);
+        softmax_into(out_row, shift_of(in_row));
+    }
+    Ok(())
+}
+
+/// Computes the softmax of each row of a row-major `rows` x `cols` buffer, overwriting `input`
+/// with the results.
+pub fn softmax_rows_in_place<AF: AccelerateFloat>(
+    input: &mut [AF], rows: usize, cols: usize
+) -> Result<(), AccelerateError> {
+    check_lengths(("rows * cols", matrix_len(rows, cols, input.len())?), &[("input", input.len())])?;
+    if cols == 0 {
+        return Ok(());
+    }
+    for row in input.chunks_exact_mut(cols) {
+        let shift = shift_of(row);
+        softmax_into(row, shift);
+    }
+    Ok(())
+}
+
+/// Computes the log-softmax of each row of a row-major `rows` x `cols` buffer, writing the
+/// results to `out`.
+pub fn log_softmax_rows<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF], rows: usize, cols: usize
+) -> Result<(), AccelerateError> {
+    check_lengths(("rows * cols", matrix_len(rows, cols, input.len())?), &[("input", input.len()), ("out", out.len())])?;
+    if cols == 0 {
+        return Ok(());
+    }
+    for (out_row, in_row) in out.chunks_exact_mut(cols).zip(input.chunks_exact(cols)) {
+        out_row.copy_from_slice(in_row);
+        log_softmax_into(out_row);
+    }
+    Ok(())
+}
+
+/// Computes the log-softmax of each row of a row-major `rows` x `cols` buffer, overwriting
+/// `input` with the results.
+pub fn log_softmax_rows_in_place<AF: AccelerateFloat>(
+    input: &mut [AF], rows: usize, cols: usize
+) -> Result<(), AccelerateError> {
+    check_lengths(("rows * cols", matrix_len(rows, cols, input.len())?), &[("input", input.len())])?;
+    if cols == 0 {
+        return Ok(());
+    }
+    for row in input.chunks_exact_mut(cols) {
+        log_softmax_into(row);
+    }
+    Ok(())
+}
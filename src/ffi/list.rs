@@ -0,0 +1,124 @@
+// The status codes and functions of the C interface, expanded into the `extern "C"` functions by
+// `ffi/mod.rs` and into the `vforce.h` header by `build.rs`.
+//
+// Each function is listed with its input arrays, followed by any scalar parameters after a
+// semicolon, and its outputs if they are other than a single array named `out`. Arrays hold
+// elements of the precision of the function unless another type is given.
+
+ffi_status! {
+    /// The call succeeded
+    VFORCE_OK = 0;
+    /// Arrays of different lengths were passed
+    VFORCE_ERROR_LENGTH_MISMATCH = 1;
+    /// An output array overlaps an input or another output in a way the function does not allow
+    VFORCE_ERROR_OVERLAP = 2;
+    /// An input lies outside the domain of the function
+    VFORCE_ERROR_DOMAIN = 3;
+    /// An output is NaN or infinite where only finite results were allowed
+    VFORCE_ERROR_NON_FINITE = 4;
+    /// The wrong number of input arrays was passed
+    VFORCE_ERROR_ARITY_MISMATCH = 5;
+    /// An array pointer was null while the length was not zero
+    VFORCE_ERROR_NULL_POINTER = 6;
+}
+
+ffi_ops! {
+    // arithmetic
+    pow(bases, exponents);
+    cbrt(input);
+    hypot(x, y);
+    hypot_scalar(x; y: AF);
+    rhypot(x, y);
+    rhypot_scalar(x; y: AF);
+    div(numerator, denominator);
+    copysign(magnitude, sign);
+    fmod(numerator, denominator);
+    remainder(numerator, denominator);
+    nextafter(input, direction);
+    ceil(input);
+    floor(input);
+    fabs(input);
+    int(input);
+    nint(input);
+    rsqrt(input);
+    sqrt(input);
+    rec(input);
+    add(a, b);
+    add_scalar(a; b: AF);
+    sub(a, b);
+    sub_scalar(a; b: AF);
+    mul(a, b);
+    mul_scalar(a; b: AF);
+    abs_diff(a, b);
+    abs_diff_scalar(a; b: AF);
+    min(a, b);
+    min_scalar(a; b: AF);
+    max(a, b);
+    max_scalar(a; b: AF);
+    neg(input);
+    sqr(input);
+    fma(a, b, c);
+    clamp(input, lo, hi);
+    lerp(a, b, t);
+    round_half_away(input);
+    round_half_even(input);
+    rint(input);
+    powi(input; n: i32);
+    fma_scalar(a; b: AF, c: AF);
+    clamp_scalar(input; lo: AF, hi: AF);
+    lerp_scalar(a, b; t: AF);
+    scalbn(input; n: i32);
+    modf(input) -> (int_out, frac_out);
+    round_to_multiple(input; step: AF);
+    round_to_decimals(input; decimals: i32);
+    frexp(input) -> (mantissa_out, exponent_out: i32);
+    ldexp(input, exponents: i32);
+    ilogb(input) -> (out: i32);
+
+    // exponential
+    exp(input);
+    exp2(input);
+    expm1(input);
+    log(input);
+    log1p(input);
+    log2(input);
+    log10(input);
+    logb(input);
+    exp10(input);
+    exp2m1(input);
+    exp10m1(input);
+    logn(input; base: AF);
+    softmax(input);
+    log_softmax(input);
+
+    // trig
+    sin(input);
+    sinpi(input);
+    cos(input);
+    cospi(input);
+    tan(input);
+    tanpi(input);
+    asin(input);
+    acos(input);
+    atan(input);
+    atan2(y, x);
+    sincos(input) -> (sin_out, cos_out);
+    sincospi(input) -> (sin_out, cos_out);
+    sind(input);
+    cosd(input);
+    tand(input);
+    sincosd(input) -> (sin_out, cos_out);
+    asind(input);
+    acosd(input);
+    atand(input);
+    atan2d(y, x);
+    cosisin(input) -> (out: Complex);
+
+    // hyperbolic
+    sinh(input);
+    cosh(input);
+    tanh(input);
+    asinh(input);
+    acosh(input);
+    atanh(input);
+}
@@ -0,0 +1,124 @@
+//! C interface to the [`raw`](crate::raw) functions, exporting each as `vforce_<name>_f32` and
+//! `vforce_<name>_f64`
+//!
+//! The functions take their input arrays, then their output arrays, then the shared element
+//! count as a `size_t`, then any scalar parameters, and return one of the `VFORCE_*` status
+//! codes:
+//!
+//! ```c
+//! #include "vforce.h"
+//!
+//! double x[3] = {0.0, 1.0, 2.0};
+//! double y[3];
+//! if (vforce_sin_f64(x, y, 3) != VFORCE_OK) { /* ... */ }
+//! ```
+//!
+//! Arrays of any length are processed, in chunks where the backend requires it, and aliasing is
+//! checked as described in the [`raw`](crate::raw) module. Pointers may be null when the length
+//! is zero.
+//!
+//! The build script writes the declarations of every function to `vforce.h`, in the build
+//! script's `OUT_DIR` and next to the compiled library in the target directory. A static or
+//! dynamic library for linking from C can be built with
+//! `cargo rustc --release --features ffi --crate-type staticlib` (or `cdylib`). The feature
+//! enables `std`, which provides the panic handler such a library needs.
+use super::*;
+use core::ffi::c_int;
+
+macro_rules! ffi_status {
+    ($($(#[doc = $doc:literal])* $name:ident = $code:literal;)*) => {
+        $(
+            $(#[doc = $doc])*
+            pub const $name: c_int = $code;
+        )*
+    };
+}
+
+/// Resolves to the element type of an array, which is the precision of the function unless
+/// another is given
+macro_rules! element {
+    () => { AF };
+    ($ty:ident) => { $ty };
+}
+
+macro_rules! ffi_fn {
+    (
+    $suffix:literal, $name:ident,
+    ($($input:ident $(: $input_ty:ident)?),+),
+    ($($param:ident: $param_ty:ident),*),
+    ()
+    ) => {
+        ffi_fn!($suffix, $name, ($($input $(: $input_ty)?),+), ($($param: $param_ty),*), (out));
+    };
+    (
+    $suffix:literal, $name:ident,
+    ($($input:ident $(: $input_ty:ident)?),+),
+    ($($param:ident: $param_ty:ident),*),
+    ($($out:ident $(: $out_ty:ident)?),+)
+    ) => {
+        #[doc = concat!("C form of [`raw::", stringify!($name), "`](crate::raw::", stringify!($name), "), returning a status code.")]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "Every pointer must be null or aligned and valid for `len` elements, and no other reference"]
+        #[doc = "to the memory behind the outputs may be live during the call."]
+        #[unsafe(export_name = concat!("vforce_", stringify!($name), $suffix))]
+        pub unsafe extern "C" fn $name(
+            $($input: *const element!($($input_ty)?),)+
+            $($out: *mut element!($($out_ty)?),)+
+            len: usize
+            $(, $param: $param_ty)*
+        ) -> c_int {
+            if len == 0 {
+                return VFORCE_OK;
+            }
+            if $($input.is_null() ||)+ $($out.is_null() ||)+ false {
+                return VFORCE_ERROR_NULL_POINTER;
+            }
+            status(unsafe { crate::raw::$name($($out,)+ $($input,)+ len $(, $param)*) })
+        }
+    };
+}
+
+macro_rules! ffi_ops {
+    (
+    $(
+    $name:ident($($input:ident $(: $input_ty:ident)?),+ $(; $($param:ident: $param_ty:ident),+)?)
+    $(-> ($($out:ident $(: $out_ty:ident)?),+))?;
+    )*
+    ) => {
+        /// The single-precision functions, exported with the suffix `_f32`
+        pub mod f32 {
+            use super::*;
+            type AF = core::primitive::f32;
+            type Complex = AccelerateComplex<core::primitive::f32>;
+            $(
+                ffi_fn!("_f32", $name, ($($input $(: $input_ty)?),+), ($($($param: $param_ty),+)?), ($($($out $(: $out_ty)?),+)?));
+            )*
+        }
+
+        /// The double-precision functions, exported with the suffix `_f64`
+        pub mod f64 {
+            use super::*;
+            type AF = core::primitive::f64;
+            type Complex = AccelerateComplex<core::primitive::f64>;
+            $(
+                ffi_fn!("_f64", $name, ($($input $(: $input_ty)?),+), ($($($param: $param_ty),+)?), ($($($out $(: $out_ty)?),+)?));
+            )*
+        }
+    };
+}
+
+include!("list.rs");
+
+/// Returns the status code of the outcome of a call.
+pub fn status(result: Result<(), AccelerateError>) -> c_int {
+    match result {
+        Ok(()) => VFORCE_OK,
+        Err(AccelerateError::LengthMismatch { .. }) => VFORCE_ERROR_LENGTH_MISMATCH,
+        Err(AccelerateError::Overlap { .. }) => VFORCE_ERROR_OVERLAP,
+        Err(AccelerateError::DomainError { .. }) => VFORCE_ERROR_DOMAIN,
+        Err(AccelerateError::NonFinite { .. }) => VFORCE_ERROR_NON_FINITE,
+        Err(AccelerateError::ArityMismatch { .. }) => VFORCE_ERROR_ARITY_MISMATCH,
+    }
+}
@@ -0,0 +1,385 @@
+//! Fast Fourier transforms of complex and real buffers of any length
+//!
+//! A plan is made once for each length, factoring it and computing its table of twiddle factors
+//! with [`cosisin_array`], and can then transform any number of buffers of that length. Lengths
+//! are factored into radices 4, 2, 3, 5 and any larger primes, and transformed by the
+//! mixed-radix Cooley-Tukey algorithm, so lengths with only small prime factors are fastest: a
+//! prime factor p costs time proportional to p for every element.
+//!
+//! The forward transform computes X[k] = sum(x[n] exp(-2 pi i k n / len)), and the inverse
+//! transform includes the factor 1 / len, so that it undoes the forward transform, as in NumPy.
+//!
+//! ```rust
+//! use vforce::AccelerateComplex;
+//! use vforce::fft::FftPlan;
+//!
+//! let plan = FftPlan::<f64>::new(4);
+//! let mut buffer = [1.0, 0.0, -1.0, 0.0].map(|x| AccelerateComplex::new(x, 0.0));
+//! plan.forward(&mut buffer).unwrap();
+//! assert_eq!(buffer[1].unpack(), (2.0, 0.0));
+//!
+//! plan.inverse(&mut buffer).unwrap();
+//! assert_eq!(buffer[2].unpack(), (-1.0, 0.0));
+//! ```
+use super::*;
+use crate::trig::cosisin_array;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Complex arithmetic on the components of an [`AccelerateComplex`], with the same layout
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Cx<AF> {
+    re: AF,
+    im: AF,
+}
+
+impl<AF: AccelerateFloat> Cx<AF> {
+    const ZERO: Self = Self { re: AF::ZERO, im: AF::ZERO };
+
+    fn of(z: AccelerateComplex<AF>) -> Self {
+        let (re, im) = z.unpack();
+        Self { re, im }
+    }
+
+    fn pack(self) -> AccelerateComplex<AF> {
+        AccelerateComplex::new(self.re, self.im)
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self { re: self.re + other.re, im: self.im + other.im }
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Self { re: self.re - other.re, im: self.im - other.im }
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            re: self.re.mul_add(other.re, -(self.im * other.im)),
+            im: self.re.mul_add(other.im, self.im * other.re),
+        }
+    }
+
+    fn scale(self, factor: AF) -> Self {
+        Self { re: self.re * factor, im: self.im * factor }
+    }
+
+    fn conj(self) -> Self {
+        Self { re: self.re, im: -self.im }
+    }
+
+    /// Multiplies by -i.
+    fn mul_neg_i(self) -> Self {
+        Self { re: self.im, im: -self.re }
+    }
+}
+
+/// Computes exp(-2 pi i k / len) for each k in `0..count` with [`cosisin_array`].
+fn twiddles<AF: AccelerateFloat>(len: usize, count: usize) -> Vec<Cx<AF>> {
+    let angles: Vec<AF> = (0..count)
+        .map(|k| AF::from_f64(-2.0 * core::f64::consts::PI * (k as f64 / len as f64)))
+        .collect();
+    let mut table = vec![AccelerateComplex::new(AF::ZERO, AF::ZERO); count];
+    cosisin_array(&mut table, &angles).expect("the table and angles have the same length");
+    table.into_iter().map(Cx::of).collect()
+}
+
+/// Splits `len` into the radices the transform proceeds by.
+fn factor(mut len: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    for radix in [4, 2, 3] {
+        while len.is_multiple_of(radix) && len > 1 {
+            factors.push(radix);
+            len /= radix;
+        }
+    }
+    let mut p = 5;
+    while len > 1 {
+        if p * p > len {
+            factors.push(len);
+            break;
+        }
+        while len.is_multiple_of(p) {
+            factors.push(p);
+            len /= p;
+        }
+        p += 2;
+    }
+    factors
+}
+
+/// Returns `AccelerateError::LengthMismatch` if `got` is not the length a plan was made for.
+fn check_plan_len(argument: &'static str, expected: usize, got: usize) -> Result<(), AccelerateError> {
+    if expected != got {
+        return Err(AccelerateError::LengthMismatch { argument, reference: "plan", expected, got });
+    }
+    Ok(())
+}
+
+/// A plan for the complex transforms of buffers of one length
+pub struct FftPlan<AF> {
+    len: usize,
+    factors: Vec<usize>,
+    twiddles: Vec<Cx<AF>>,
+}
+
+impl<AF: AccelerateFloat> FftPlan<AF> {
+    /// Plans transforms of `len` elements.
+    pub fn new(len: usize) -> Self {
+        Self { len, factors: factor(len), twiddles: twiddles(len, len) }
+    }
+
+    /// The length of the buffers the plan transforms
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the plan transforms empty buffers, which it leaves unchanged
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The length of the scratch buffer [`forward_with_scratch`](Self::forward_with_scratch)
+    /// and [`inverse_with_scratch`](Self::inverse_with_scratch) take
+    pub fn scratch_len(&self) -> usize {
+        self.len + self.factors.iter().max().copied().unwrap_or(0)
+    }
+
+    /// Replaces `buffer` with its discrete Fourier transform, allocating the scratch space needed.
+    ///
+    /// Returns `AccelerateError::LengthMismatch` if `buffer` is not of the planned length.
+    pub fn forward(&self, buffer: &mut [AccelerateComplex<AF>]) -> Result<(), AccelerateError> {
+        self.forward_with_scratch(buffer, &mut vec![AccelerateComplex::new(AF::ZERO, AF::ZERO); self.scratch_len()])
+    }
+
+    /// Replaces `buffer` with its inverse discrete Fourier transform, allocating the scratch
+    /// space needed.
+    ///
+    /// Returns `AccelerateError::LengthMismatch` if `buffer` is not of the planned length.
+    pub fn inverse(&self, buffer: &mut [AccelerateComplex<AF>]) -> Result<(), AccelerateError> {
+        self.inverse_with_scratch(buffer, &mut vec![AccelerateComplex::new(AF::ZERO, AF::ZERO); self.scratch_len()])
+    }
+
+    /// Replaces `buffer` with its discrete Fourier transform, using `scratch`, of
+    /// [`scratch_len`](Self::scratch_len) elements, as working space.
+    pub fn forward_with_scratch(
+        &self, buffer: &mut [AccelerateComplex<AF>], scratch: &mut [AccelerateComplex<AF>]
+    ) -> Result<(), AccelerateError> {
+        self.process(buffer, scratch, false)
+    }
+
+    /// Replaces `buffer` with its inverse discrete Fourier transform, using `scratch`, of
+    /// [`scratch_len`](Self::scratch_len) elements, as working space.
+    pub fn inverse_with_scratch(
+        &self, buffer: &mut [AccelerateComplex<AF>], scratch: &mut [AccelerateComplex<AF>]
+    ) -> Result<(), AccelerateError> {
+        self.process(buffer, scratch, true)
+    }
+
+    fn process(
+        &self, buffer: &mut [AccelerateComplex<AF>], scratch: &mut [AccelerateComplex<AF>], inverse: bool
+    ) -> Result<(), AccelerateError> {
+        check_plan_len("buffer", self.len, buffer.len())?;
+        check_plan_len("scratch", self.scratch_len(), scratch.len())?;
+        if self.len == 0 {
+            return Ok(());
+        }
+        // the input is copied to the scratch space, from which the transform reads, and the
+        // rest of the scratch space holds the inputs to one butterfly
+        let (input, butterfly) = scratch.split_at_mut(self.len);
+        input.copy_from_slice(buffer);
+        let input = Cx::slice(input);
+        self.transform(Cx::slice_mut(buffer), input, 0, 1, &self.factors, inverse, Cx::slice_mut(butterfly));
+        if inverse {
+            let scale = AF::ONE / AF::from_f64(self.len as f64);
+            for z in Cx::slice_mut(buffer) {
+                *z = z.scale(scale);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the twiddle factor exp(-2 pi i k / len), or its conjugate for the inverse
+    /// transform.
+    fn twiddle(&self, k: usize, inverse: bool) -> Cx<AF> {
+        let w = self.twiddles[k];
+        if inverse { w.conj() } else { w }
+    }
+
+    /// Writes to `out` the transform of the `out.len()` elements of `input` at `offset`,
+    /// `offset + stride`, and so on, whose length factors into `factors`.
+    #[allow(clippy::too_many_arguments)]
+    fn transform(
+        &self, out: &mut [Cx<AF>], input: &[Cx<AF>], offset: usize, stride: usize, factors: &[usize],
+        inverse: bool, butterfly: &mut [Cx<AF>]
+    ) {
+        let Some((&p, rest)) = factors.split_first() else {
+            out[0] = input[offset];
+            return;
+        };
+        let n = out.len();
+        let m = n / p;
+        // transform the p subsequences of every p-th element, then combine them with butterflies
+        for (r, part) in out.chunks_exact_mut(m).enumerate() {
+            self.transform(part, input, offset + r * stride, stride * p, rest, inverse, butterfly);
+        }
+        let step = self.len / n;
+        let t = &mut butterfly[..p];
+        for k in 0..m {
+            for (r, t) in t.iter_mut().enumerate() {
+                *t = out[r * m + k].mul(self.twiddle(r * k * step, inverse));
+            }
+            match *t {
+                [a, b] => {
+                    out[k] = a.add(b);
+                    out[m + k] = a.sub(b);
+                }
+                [a, b, c, d] => {
+                    let (s0, d0) = (a.add(c), a.sub(c));
+                    let (s1, d1) = (b.add(d), b.sub(d));
+                    let d1 = if inverse { d1.mul_neg_i().scale(-AF::ONE) } else { d1.mul_neg_i() };
+                    out[k] = s0.add(s1);
+                    out[m + k] = d0.add(d1);
+                    out[2 * m + k] = s0.sub(s1);
+                    out[3 * m + k] = d0.sub(d1);
+                }
+                _ => {
+                    let root = self.len / p;
+                    for q in 0..p {
+                        out[q * m + k] = t.iter()
+                            .enumerate()
+                            .fold(Cx::ZERO, |sum, (r, &x)| sum.add(x.mul(self.twiddle(r * q % p * root, inverse))));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<AF> Cx<AF> {
+    fn slice(zs: &[AccelerateComplex<AF>]) -> &[Self] where AF: Copy {
+        // SAFETY: AccelerateComplex is repr(C) with the real and imaginary parts in that order,
+        // as is Cx
+        unsafe { core::slice::from_raw_parts(zs.as_ptr().cast(), zs.len()) }
+    }
+
+    fn slice_mut(zs: &mut [AccelerateComplex<AF>]) -> &mut [Self] where AF: Copy {
+        // SAFETY: as for `slice`
+        unsafe { core::slice::from_raw_parts_mut(zs.as_mut_ptr().cast(), zs.len()) }
+    }
+}
+
+/// A plan for the transforms of real buffers of one length to and from the non-negative half of
+/// their spectrum, which holds `len / 2 + 1` elements
+pub struct RealFftPlan<AF> {
+    len: usize,
+    /// The plan for half the length if that is even, in which case pairs of real elements are
+    /// transformed as one complex element, or for the full length if it is odd
+    complex: FftPlan<AF>,
+    /// exp(-2 pi i k / len) for k up to len / 2, if the length is even
+    twiddles: Vec<Cx<AF>>,
+}
+
+impl<AF: AccelerateFloat> RealFftPlan<AF> {
+    /// Plans transforms of `len` real elements.
+    pub fn new(len: usize) -> Self {
+        if len.is_multiple_of(2) {
+            Self { len, complex: FftPlan::new(len / 2), twiddles: twiddles(len, len / 2 + 1) }
+        } else {
+            Self { len, complex: FftPlan::new(len), twiddles: Vec::new() }
+        }
+    }
+
+    /// The length of the real buffers the plan transforms
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the plan transforms empty buffers
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The length of the spectra the plan produces and consumes, `len / 2 + 1`, or zero for an
+    /// empty plan
+    pub fn spectrum_len(&self) -> usize {
+        if self.len == 0 { 0 } else { self.len / 2 + 1 }
+    }
+
+    /// Writes the first [`spectrum_len`](Self::spectrum_len) elements of the discrete Fourier
+    /// transform of `input` to `out`; the rest are their conjugates.
+    pub fn forward(&self, out: &mut [AccelerateComplex<AF>], input: &[AF]) -> Result<(), AccelerateError> {
+        check_plan_len("input", self.len, input.len())?;
+        check_plan_len("out", self.spectrum_len(), out.len())?;
+        if self.len == 0 {
+            return Ok(());
+        }
+        if self.len % 2 == 1 {
+            let mut buffer: Vec<_> = input.iter().map(|&x| AccelerateComplex::new(x, AF::ZERO)).collect();
+            self.complex.forward(&mut buffer)?;
+            out.copy_from_slice(&buffer[..out.len()]);
+            return Ok(());
+        }
+
+        // z[n] = x[2n] + i x[2n + 1], whose transform Z holds those of the even elements, E, and
+        // the odd elements, O, as Z[k] = E[k] + i O[k], and X[k] = E[k] + exp(-2 pi i k / len) O[k]
+        let half = self.len / 2;
+        let mut z: Vec<_> = input.chunks_exact(2).map(|x| AccelerateComplex::new(x[0], x[1])).collect();
+        self.complex.forward(&mut z)?;
+        let z = Cx::slice(&z);
+        let one_half = AF::from_f64(0.5);
+        for (k, o) in out.iter_mut().enumerate() {
+            let (a, b) = (z[k % half], z[(half - k) % half].conj());
+            let even = a.add(b).scale(one_half);
+            let odd = a.sub(b).scale(one_half).mul_neg_i();
+            *o = even.add(self.twiddles[k].mul(odd)).pack();
+        }
+        Ok(())
+    }
+
+    /// Writes to `out` the real inverse discrete Fourier transform of the spectrum whose first
+    /// [`spectrum_len`](Self::spectrum_len) elements are `spectrum`.
+    ///
+    /// The imaginary parts of the first element and, for even lengths, the last, which are zero
+    /// in the spectrum of any real buffer, are ignored.
+    pub fn inverse(&self, out: &mut [AF], spectrum: &[AccelerateComplex<AF>]) -> Result<(), AccelerateError> {
+        check_plan_len("out", self.len, out.len())?;
+        check_plan_len("spectrum", self.spectrum_len(), spectrum.len())?;
+        if self.len == 0 {
+            return Ok(());
+        }
+        let spectrum = Cx::slice(spectrum);
+        if self.len % 2 == 1 {
+            let mut buffer: Vec<_> = (0..self.len)
+                .map(|k| if k < spectrum.len() { spectrum[k] } else { spectrum[self.len - k].conj() })
+                .map(Cx::pack)
+                .collect();
+            self.complex.inverse(&mut buffer)?;
+            for (o, z) in out.iter_mut().zip(&buffer) {
+                *o = z.unpack().0;
+            }
+            return Ok(());
+        }
+
+        // undoes the combination in `forward`, recovering Z[k] = E[k] + i O[k]
+        let half = self.len / 2;
+        let one_half = AF::from_f64(0.5);
+        // the first and last elements are taken as real, as they would otherwise leak into every
+        // output element through the combination
+        let at = |k: usize| if k == 0 || k == half { Cx { re: spectrum[k].re, im: AF::ZERO } } else { spectrum[k] };
+        let mut z: Vec<_> = (0..half)
+            .map(|k| {
+                let (a, b) = (at(k), at(half - k).conj());
+                let even = a.add(b).scale(one_half);
+                let odd = a.sub(b).scale(one_half).mul(self.twiddles[k].conj());
+                even.sub(odd.mul_neg_i()).pack()
+            })
+            .collect();
+        self.complex.inverse(&mut z)?;
+        for (pair, z) in out.chunks_exact_mut(2).zip(&z) {
+            (pair[0], pair[1]) = z.unpack();
+        }
+        Ok(())
+    }
+}
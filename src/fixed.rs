@@ -0,0 +1,157 @@
+//! Fixed-size forms of the array functions, for callers working with `[AF; N]` buffers
+//!
+//! Every array has the same const length `N`, so length mismatches are ruled out at compile time
+//! and these functions return no `Result`. Each function comes in a form writing to `out`, and a
+//! `_value` form returning the results by value.
+//!
+//! ```rust
+//! use vforce::fixed::{pow_fixed, sin_fixed_value};
+//! let bases = [1.0f64, 6.0, 2.5];
+//! let exponents = [2.0f64, 4.0, 1.3];
+//! let mut out = [0.0f64; 3];
+//!
+//! pow_fixed(&mut out, &bases, &exponents);
+//! assert_eq!(out, [1.0, 1296.0, 2.5f64.powf(1.3)]);
+//!
+//! let sines = sin_fixed_value(&[0.0f32; 4]);
+//! assert_eq!(sines, [0.0; 4]);
+//! ```
+use super::*;
+
+macro_rules! fixed_op {
+    (
+    $name:ident, $value_name:ident, $array:path,
+    ($out:ident: $out_ty:ty = $init:expr),
+    ($($arg:ident: $arg_ty:ty),*)
+    ) => {
+        #[doc = concat!("Fixed-size form of [`", stringify!($array), "`], writing the results to `", stringify!($out), "`.")]
+        pub fn $name<AF: AccelerateFloat, const N: usize>($out: &mut [$out_ty; N], $($arg: $arg_ty),*) {
+            infallible($array($out, $($arg),*));
+        }
+        #[doc = concat!("Fixed-size form of [`", stringify!($array), "`], returning the results by value.")]
+        pub fn $value_name<AF: AccelerateFloat, const N: usize>($($arg: $arg_ty),*) -> [$out_ty; N] {
+            let mut $out = [$init; N];
+            $name(&mut $out, $($arg),*);
+            $out
+        }
+    };
+    (
+    $name:ident, $value_name:ident, $array:path,
+    ($($out:ident: $out_ty:ty = $init:expr),+),
+    ($($arg:ident: $arg_ty:ty),*)
+    ) => {
+        #[doc = concat!("Fixed-size form of [`", stringify!($array), "`], taking its output arrays in the same order.")]
+        pub fn $name<AF: AccelerateFloat, const N: usize>($($out: &mut [$out_ty; N]),+, $($arg: $arg_ty),*) {
+            infallible($array($($out),+, $($arg),*));
+        }
+        #[doc = concat!("Fixed-size form of [`", stringify!($array), "`], returning the results by value as a tuple in the order of its output arrays.")]
+        pub fn $value_name<AF: AccelerateFloat, const N: usize>($($arg: $arg_ty),*) -> ($([$out_ty; N]),+) {
+            $(let mut $out = [$init; N];)+
+            $name($(&mut $out),+, $($arg),*);
+            ($($out),+)
+        }
+    };
+}
+
+// ── arithmetic ──
+
+fixed_op!(pow_fixed, pow_fixed_value, crate::arithmetic::pow_array, (out: AF = AF::ZERO), (bases: &[AF; N], exponents: &[AF; N]));
+fixed_op!(cbrt_fixed, cbrt_fixed_value, crate::arithmetic::cbrt_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(hypot_fixed, hypot_fixed_value, crate::arithmetic::hypot_array, (out: AF = AF::ZERO), (x: &[AF; N], y: &[AF; N]));
+fixed_op!(hypot_scalar_fixed, hypot_scalar_fixed_value, crate::arithmetic::hypot_scalar_array, (out: AF = AF::ZERO), (x: &[AF; N], y: AF));
+fixed_op!(rhypot_fixed, rhypot_fixed_value, crate::arithmetic::rhypot_array, (out: AF = AF::ZERO), (x: &[AF; N], y: &[AF; N]));
+fixed_op!(rhypot_scalar_fixed, rhypot_scalar_fixed_value, crate::arithmetic::rhypot_scalar_array, (out: AF = AF::ZERO), (x: &[AF; N], y: AF));
+fixed_op!(div_fixed, div_fixed_value, crate::arithmetic::div_array, (out: AF = AF::ZERO), (numerator: &[AF; N], denominator: &[AF; N]));
+fixed_op!(copysign_fixed, copysign_fixed_value, crate::arithmetic::copysign_array, (out: AF = AF::ZERO), (magnitude: &[AF; N], sign: &[AF; N]));
+fixed_op!(fmod_fixed, fmod_fixed_value, crate::arithmetic::fmod_array, (out: AF = AF::ZERO), (numerator: &[AF; N], denominator: &[AF; N]));
+fixed_op!(remainder_fixed, remainder_fixed_value, crate::arithmetic::remainder_array, (out: AF = AF::ZERO), (numerator: &[AF; N], denominator: &[AF; N]));
+fixed_op!(nextafter_fixed, nextafter_fixed_value, crate::arithmetic::nextafter_array, (out: AF = AF::ZERO), (input: &[AF; N], direction: &[AF; N]));
+fixed_op!(ceil_fixed, ceil_fixed_value, crate::arithmetic::ceil_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(floor_fixed, floor_fixed_value, crate::arithmetic::floor_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(fabs_fixed, fabs_fixed_value, crate::arithmetic::fabs_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(int_fixed, int_fixed_value, crate::arithmetic::int_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(nint_fixed, nint_fixed_value, crate::arithmetic::nint_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(rsqrt_fixed, rsqrt_fixed_value, crate::arithmetic::rsqrt_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(sqrt_fixed, sqrt_fixed_value, crate::arithmetic::sqrt_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(rec_fixed, rec_fixed_value, crate::arithmetic::rec_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(add_fixed, add_fixed_value, crate::arithmetic::add_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N]));
+fixed_op!(add_scalar_fixed, add_scalar_fixed_value, crate::arithmetic::add_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF));
+fixed_op!(sub_fixed, sub_fixed_value, crate::arithmetic::sub_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N]));
+fixed_op!(sub_scalar_fixed, sub_scalar_fixed_value, crate::arithmetic::sub_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF));
+fixed_op!(mul_fixed, mul_fixed_value, crate::arithmetic::mul_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N]));
+fixed_op!(mul_scalar_fixed, mul_scalar_fixed_value, crate::arithmetic::mul_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF));
+fixed_op!(abs_diff_fixed, abs_diff_fixed_value, crate::arithmetic::abs_diff_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N]));
+fixed_op!(abs_diff_scalar_fixed, abs_diff_scalar_fixed_value, crate::arithmetic::abs_diff_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF));
+fixed_op!(min_fixed, min_fixed_value, crate::arithmetic::min_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N]));
+fixed_op!(min_scalar_fixed, min_scalar_fixed_value, crate::arithmetic::min_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF));
+fixed_op!(max_fixed, max_fixed_value, crate::arithmetic::max_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N]));
+fixed_op!(max_scalar_fixed, max_scalar_fixed_value, crate::arithmetic::max_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF));
+fixed_op!(neg_fixed, neg_fixed_value, crate::arithmetic::neg_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(sqr_fixed, sqr_fixed_value, crate::arithmetic::sqr_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(fma_fixed, fma_fixed_value, crate::arithmetic::fma_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N], c: &[AF; N]));
+fixed_op!(clamp_fixed, clamp_fixed_value, crate::arithmetic::clamp_array, (out: AF = AF::ZERO), (input: &[AF; N], lo: &[AF; N], hi: &[AF; N]));
+fixed_op!(lerp_fixed, lerp_fixed_value, crate::arithmetic::lerp_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N], t: &[AF; N]));
+fixed_op!(round_half_away_fixed, round_half_away_fixed_value, crate::arithmetic::round_half_away_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(round_half_even_fixed, round_half_even_fixed_value, crate::arithmetic::round_half_even_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(rint_fixed, rint_fixed_value, crate::arithmetic::rint_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(powi_fixed, powi_fixed_value, crate::arithmetic::powi_array, (out: AF = AF::ZERO), (input: &[AF; N], n: i32));
+fixed_op!(fma_scalar_fixed, fma_scalar_fixed_value, crate::arithmetic::fma_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: AF, c: AF));
+fixed_op!(clamp_scalar_fixed, clamp_scalar_fixed_value, crate::arithmetic::clamp_scalar_array, (out: AF = AF::ZERO), (input: &[AF; N], lo: AF, hi: AF));
+fixed_op!(lerp_scalar_fixed, lerp_scalar_fixed_value, crate::arithmetic::lerp_scalar_array, (out: AF = AF::ZERO), (a: &[AF; N], b: &[AF; N], t: AF));
+fixed_op!(frexp_fixed, frexp_fixed_value, crate::arithmetic::frexp_array, (mantissa_out: AF = AF::ZERO, exponent_out: i32 = 0), (input: &[AF; N]));
+fixed_op!(ldexp_fixed, ldexp_fixed_value, crate::arithmetic::ldexp_array, (out: AF = AF::ZERO), (input: &[AF; N], exponents: &[i32; N]));
+fixed_op!(scalbn_fixed, scalbn_fixed_value, crate::arithmetic::scalbn_array, (out: AF = AF::ZERO), (input: &[AF; N], n: i32));
+fixed_op!(ilogb_fixed, ilogb_fixed_value, crate::arithmetic::ilogb_array, (out: i32 = 0), (input: &[AF; N]));
+fixed_op!(modf_fixed, modf_fixed_value, crate::arithmetic::modf_array, (int_out: AF = AF::ZERO, frac_out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(round_to_multiple_fixed, round_to_multiple_fixed_value, crate::arithmetic::round_to_multiple_array, (out: AF = AF::ZERO), (input: &[AF; N], step: AF));
+fixed_op!(round_to_decimals_fixed, round_to_decimals_fixed_value, crate::arithmetic::round_to_decimals_array, (out: AF = AF::ZERO), (input: &[AF; N], decimals: i32));
+
+// ── exponential ──
+
+fixed_op!(exp_fixed, exp_fixed_value, crate::exponential::exp_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(exp2_fixed, exp2_fixed_value, crate::exponential::exp2_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(expm1_fixed, expm1_fixed_value, crate::exponential::expm1_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(log_fixed, log_fixed_value, crate::exponential::log_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(log1p_fixed, log1p_fixed_value, crate::exponential::log1p_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(log2_fixed, log2_fixed_value, crate::exponential::log2_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(log10_fixed, log10_fixed_value, crate::exponential::log10_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(logb_fixed, logb_fixed_value, crate::exponential::logb_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(exp10_fixed, exp10_fixed_value, crate::exponential::exp10_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(exp2m1_fixed, exp2m1_fixed_value, crate::exponential::exp2m1_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(exp10m1_fixed, exp10m1_fixed_value, crate::exponential::exp10m1_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(logn_fixed, logn_fixed_value, crate::exponential::logn_array, (out: AF = AF::ZERO), (input: &[AF; N], base: AF));
+fixed_op!(softmax_fixed, softmax_fixed_value, crate::exponential::softmax_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(log_softmax_fixed, log_softmax_fixed_value, crate::exponential::log_softmax_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+
+// ── trig ──
+
+fixed_op!(sin_fixed, sin_fixed_value, crate::trig::sin_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(sinpi_fixed, sinpi_fixed_value, crate::trig::sinpi_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(cos_fixed, cos_fixed_value, crate::trig::cos_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(cospi_fixed, cospi_fixed_value, crate::trig::cospi_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(tan_fixed, tan_fixed_value, crate::trig::tan_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(tanpi_fixed, tanpi_fixed_value, crate::trig::tanpi_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(asin_fixed, asin_fixed_value, crate::trig::asin_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(acos_fixed, acos_fixed_value, crate::trig::acos_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(atan_fixed, atan_fixed_value, crate::trig::atan_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(atan2_fixed, atan2_fixed_value, crate::trig::atan2_array, (out: AF = AF::ZERO), (y: &[AF; N], x: &[AF; N]));
+fixed_op!(sincos_fixed, sincos_fixed_value, crate::trig::sincos_array, (sin_out: AF = AF::ZERO, cos_out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(sincospi_fixed, sincospi_fixed_value, crate::trig::sincospi_array, (sin_out: AF = AF::ZERO, cos_out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(sind_fixed, sind_fixed_value, crate::trig::sind_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(cosd_fixed, cosd_fixed_value, crate::trig::cosd_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(tand_fixed, tand_fixed_value, crate::trig::tand_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(sincosd_fixed, sincosd_fixed_value, crate::trig::sincosd_array, (sin_out: AF = AF::ZERO, cos_out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(asind_fixed, asind_fixed_value, crate::trig::asind_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(acosd_fixed, acosd_fixed_value, crate::trig::acosd_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(atand_fixed, atand_fixed_value, crate::trig::atand_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(atan2d_fixed, atan2d_fixed_value, crate::trig::atan2d_array, (out: AF = AF::ZERO), (y: &[AF; N], x: &[AF; N]));
+fixed_op!(cosisin_fixed, cosisin_fixed_value, crate::trig::cosisin_array, (out: AccelerateComplex<AF> = AccelerateComplex::new(AF::ZERO, AF::ZERO)), (input: &[AF; N]));
+
+// ── hyperbolic ──
+
+fixed_op!(sinh_fixed, sinh_fixed_value, crate::hyperbolic::sinh_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(cosh_fixed, cosh_fixed_value, crate::hyperbolic::cosh_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(tanh_fixed, tanh_fixed_value, crate::hyperbolic::tanh_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(asinh_fixed, asinh_fixed_value, crate::hyperbolic::asinh_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(acosh_fixed, acosh_fixed_value, crate::hyperbolic::acosh_array, (out: AF = AF::ZERO), (input: &[AF; N]));
+fixed_op!(atanh_fixed, atanh_fixed_value, crate::hyperbolic::atanh_array, (out: AF = AF::ZERO), (input: &[AF; N]));
@@ -12,6 +12,12 @@ unary_vforce_op!(
     /// If x is +/-0, the result preserves the signed zero.
     /// If x is +/-inf, the result is +/-inf.
     sinh_array_in_place,
+    /// Computes the hyperbolic sine of each element, writing the results to `out` where `mask` is
+    /// set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If x is +/-inf, the result is +/-inf.
+    sinh_array_masked,
     accelerate_sinh, input);
 
 unary_vforce_op!(
@@ -25,6 +31,12 @@ unary_vforce_op!(
     /// If x is +/-0, the result is 1.
     /// If x is +/-inf, the result is +inf.
     cosh_array_in_place,
+    /// Computes the hyperbolic cosine of each element, writing the results to `out` where `mask`
+    /// is set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result is 1.
+    /// If x is +/-inf, the result is +inf.
+    cosh_array_masked,
     accelerate_cosh, input);
 
 unary_vforce_op!(
@@ -38,6 +50,12 @@ unary_vforce_op!(
     /// If x is +/-0, the result preserves the signed zero.
     /// If x is +/-inf, the result is +/-1.
     tanh_array_in_place,
+    /// Computes the hyperbolic tangent of each element, writing the results to `out` where `mask`
+    /// is set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If x is +/-inf, the result is +/-1.
+    tanh_array_masked,
     accelerate_tanh, input);
 
 unary_vforce_op!(
@@ -45,6 +63,9 @@ unary_vforce_op!(
     asinh_array,
     /// Computes the inverse hyperbolic sine of each element, overwriting `input` with the results.
     asinh_array_in_place,
+    /// Computes the inverse hyperbolic sine of each element, writing the results to `out` where
+    /// `mask` is set and leaving the other elements of `out` unchanged.
+    asinh_array_masked,
     accelerate_asinh, input);
 
 unary_vforce_op!(
@@ -62,8 +83,27 @@ unary_vforce_op!(
     /// If x < 1, the result is NaN.
     /// If x == +inf, the result is +inf.
     acosh_array_in_place,
+    /// Computes the inverse hyperbolic cosine of each element, writing the results to `out` where
+    /// `mask` is set and leaving the other elements of `out` unchanged.
+    ///
+    /// The calculated values are in the range [0, +inf].
+    /// If x == 1, the result is +0.
+    /// If x < 1, the result is NaN.
+    /// If x == +inf, the result is +inf.
+    acosh_array_masked,
     accelerate_acosh, input);
 
+checked_unary_op!(
+    /// Computes the inverse hyperbolic cosine of each element, writing the results to `out`, or
+    /// returns `AccelerateError::DomainError` for the first element with x < 1 without writing
+    /// anything.
+    acosh_array_checked,
+    /// Computes the inverse hyperbolic cosine of each element, overwriting `input` with the
+    /// results, or returns `AccelerateError::DomainError` for the first element with x < 1 without
+    /// modifying `input`.
+    acosh_array_in_place_checked,
+    acosh_array, acosh_array_in_place, crate::domain::Domain::AtLeastOne);
+
 unary_vforce_op!(
     /// Computes the inverse hyperbolic tangent of each element, writing the results to `out`.
     ///
@@ -77,4 +117,22 @@ unary_vforce_op!(
     /// If x is +/-1, the result is +/-inf.
     /// If |x|>1, the result is NaN.
     atanh_array_in_place,
+    /// Computes the inverse hyperbolic tangent of each element, writing the results to `out`
+    /// where `mask` is set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If x is +/-1, the result is +/-inf.
+    /// If |x|>1, the result is NaN.
+    atanh_array_masked,
     accelerate_atanh, input);
+
+checked_unary_op!(
+    /// Computes the inverse hyperbolic tangent of each element, writing the results to `out`, or
+    /// returns `AccelerateError::DomainError` for the first element with |x| > 1 without writing
+    /// anything.
+    atanh_array_checked,
+    /// Computes the inverse hyperbolic tangent of each element, overwriting `input` with the
+    /// results, or returns `AccelerateError::DomainError` for the first element with |x| > 1
+    /// without modifying `input`.
+    atanh_array_in_place_checked,
+    atanh_array, atanh_array_in_place, crate::domain::Domain::UnitInterval);
@@ -0,0 +1,173 @@
+//! Streaming application of the unary array functions to raw binary floats read from a
+//! [`Read`](std::io::Read) and written to a [`Write`](std::io::Write)
+//!
+//! Elements are read in blocks of [`BLOCK_LEN`], so streams of any length are processed in
+//! constant memory, and blocks can be processed on several threads at once with
+//! [`transform_parallel`].
+//!
+//! ```rust
+//! use vforce::io::{transform, Endianness, Precision};
+//! use vforce::op::Op;
+//!
+//! let input: Vec<u8> = [0.0f32, 1.0].iter().flat_map(|x| x.to_le_bytes()).collect();
+//! let mut output = Vec::new();
+//! let count = transform(&input[..], &mut output, Op::Log1p, Endianness::Little, Precision::F32).unwrap();
+//! assert_eq!(count, 2);
+//! assert_eq!(output[4..], 2.0f32.ln().to_le_bytes());
+//! ```
+use super::*;
+use crate::op::Op;
+use core::num::NonZeroUsize;
+use std::io::{self, Read, Write};
+use std::vec;
+use std::vec::Vec;
+
+/// Number of elements read, processed and written at a time
+pub const BLOCK_LEN: usize = 1 << 16;
+
+/// Byte order of the elements of a stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    Little,
+    Big,
+    /// The byte order of the target, which is little-endian on all Apple hardware
+    Native,
+}
+
+/// Element type of a stream, which is also the precision the operation is computed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// `f32`, four bytes per element
+    F32,
+    /// `f64`, eight bytes per element
+    F64,
+}
+
+/// Conversion of elements to and from bytes in either byte order
+trait Element: AccelerateFloat + Send + Sync {
+    const SIZE: usize;
+
+    fn decode(bytes: &[u8], endianness: Endianness) -> Self;
+    fn encode(self, endianness: Endianness, out: &mut [u8]);
+}
+
+macro_rules! impl_element {
+    ($ty:ty) => {
+        impl Element for $ty {
+            const SIZE: usize = size_of::<$ty>();
+
+            fn decode(bytes: &[u8], endianness: Endianness) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                match endianness {
+                    Endianness::Little => <$ty>::from_le_bytes(bytes),
+                    Endianness::Big => <$ty>::from_be_bytes(bytes),
+                    Endianness::Native => <$ty>::from_ne_bytes(bytes),
+                }
+            }
+
+            fn encode(self, endianness: Endianness, out: &mut [u8]) {
+                out.copy_from_slice(&match endianness {
+                    Endianness::Little => self.to_le_bytes(),
+                    Endianness::Big => self.to_be_bytes(),
+                    Endianness::Native => self.to_ne_bytes(),
+                });
+            }
+        }
+    };
+}
+
+impl_element!(f32);
+impl_element!(f64);
+
+/// Reads `reader` to its end as raw binary elements, applies the unary elementwise `op` to each,
+/// and writes the results to `writer` in the same format, returning the number of elements.
+///
+/// Returns an error of kind `InvalidInput` if `op` is not unary and elementwise, and of kind
+/// `InvalidData` if the stream ends partway through an element, in which case the results for
+/// every whole element have already been written.
+pub fn transform(
+    reader: impl Read, writer: impl Write, op: Op, endianness: Endianness, precision: Precision
+) -> io::Result<u64> {
+    transform_parallel(reader, writer, op, endianness, precision, NonZeroUsize::MIN)
+}
+
+/// As [`transform`], but processing up to `threads` blocks at once, each on its own thread.
+pub fn transform_parallel(
+    reader: impl Read, writer: impl Write, op: Op, endianness: Endianness, precision: Precision, threads: NonZeroUsize
+) -> io::Result<u64> {
+    if op.arity() != 1 || !op.is_elementwise() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "only unary elementwise operations can be streamed"));
+    }
+    match precision {
+        Precision::F32 => run::<f32>(reader, writer, op, endianness, threads.get()),
+        Precision::F64 => run::<f64>(reader, writer, op, endianness, threads.get()),
+    }
+}
+
+fn run<AF: Element>(
+    mut reader: impl Read, mut writer: impl Write, op: Op, endianness: Endianness, threads: usize
+) -> io::Result<u64> {
+    let mut blocks = vec![vec![0u8; BLOCK_LEN * AF::SIZE]; threads];
+    let mut lens = vec![0usize; threads];
+    let mut count = 0u64;
+    loop {
+        let mut end = false;
+        for (block, len) in blocks.iter_mut().zip(lens.iter_mut()) {
+            *len = if end { 0 } else { read_full(&mut reader, block)? };
+            end |= *len < block.len();
+        }
+        let whole: Vec<usize> = lens.iter().map(|len| len - len % AF::SIZE).collect();
+
+        if threads == 1 {
+            process::<AF>(&mut blocks[0][..whole[0]], op, endianness);
+        } else {
+            std::thread::scope(|scope| {
+                for (block, &len) in blocks.iter_mut().zip(&whole) {
+                    scope.spawn(move || process::<AF>(&mut block[..len], op, endianness));
+                }
+            });
+        }
+        for (block, &len) in blocks.iter().zip(&whole) {
+            writer.write_all(&block[..len])?;
+            count += (len / AF::SIZE) as u64;
+        }
+
+        if end {
+            writer.flush()?;
+            if lens.iter().zip(&whole).any(|(len, whole)| len != whole) {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "the stream ends partway through an element"));
+            }
+            return Ok(count);
+        }
+    }
+}
+
+/// Reads into `buf` until it is full or the stream ends, returning the number of bytes read.
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Applies `op` to the elements encoded in `bytes`, overwriting them with the results.
+fn process<AF: Element>(bytes: &mut [u8], op: Op, endianness: Endianness) {
+    let mut input = [AF::ZERO; TILE];
+    let mut out = [AF::ZERO; TILE];
+    for tile in bytes.chunks_mut(TILE * AF::SIZE) {
+        let len = tile.len() / AF::SIZE;
+        for (x, bytes) in input.iter_mut().zip(tile.chunks_exact(AF::SIZE)) {
+            *x = AF::decode(bytes, endianness);
+        }
+        op.apply(&mut out[..len], &[&input[..len]]).expect("unary operations only fail on mismatched lengths");
+        for (x, bytes) in out.iter().zip(tile.chunks_exact_mut(AF::SIZE)) {
+            x.encode(endianness, bytes);
+        }
+    }
+}
@@ -30,38 +30,135 @@
 #![no_std]
 #![cfg(target_os = "macos")]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod accelerate;
 
 use core::fmt::Display;
+use core::ops::{Add, Div, Mul, Neg, Sub};
 use accelerate::fns::*;
 
 pub use accelerate::AccelerateComplex;
 
+/// The errors returned by the functions in this crate
 #[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
 pub enum AccelerateError {
-    /// Inputs and outputs are not all the same length
-    LengthMismatch { expected: usize, got: usize },
+    /// The array `argument` has `got` elements, where `expected` were required to match the
+    /// length of `reference`
+    LengthMismatch { argument: &'static str, reference: &'static str, expected: usize, got: usize },
+    /// The arrays `first` and `second` overlap in memory where they must not
+    Overlap { first: &'static str, second: &'static str },
+    /// An input element lies outside the domain of the function
+    DomainError { index: usize, value: f64 },
+    /// An output element is infinite or NaN where a finite result was required
+    NonFinite { index: usize, value: f64 },
+    /// An operation chosen at runtime received `got` input arrays where it takes `expected`
+    ArityMismatch { expected: usize, got: usize },
+}
+
+/// Float fields are compared by bit pattern, so that errors carrying NaN values compare equal to
+/// themselves
+impl PartialEq for AccelerateError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::LengthMismatch { argument, reference, expected, got },
+                Self::LengthMismatch { argument: a, reference: r, expected: e, got: g },
+            ) => argument == a && reference == r && expected == e && got == g,
+            (Self::Overlap { first, second }, Self::Overlap { first: f, second: s }) => {
+                first == f && second == s
+            }
+            (Self::ArityMismatch { expected, got }, Self::ArityMismatch { expected: e, got: g }) => {
+                expected == e && got == g
+            }
+            (Self::DomainError { index, value }, Self::DomainError { index: i, value: v })
+            | (Self::NonFinite { index, value }, Self::NonFinite { index: i, value: v }) => {
+                index == i && value.to_bits() == v.to_bits()
+            }
+            _ => false,
+        }
+    }
 }
 
+impl Eq for AccelerateError {}
+
 impl Display for AccelerateError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::LengthMismatch { expected, got } => {
-                write!(f, "AccelerateError::LengthMismatch - vforce received arrays of different lengths: expected {} elements, got {} elements", expected, got)
+            Self::LengthMismatch { argument, reference, expected, got } => {
+                write!(f, "AccelerateError::LengthMismatch - vforce received arrays of different lengths: `{}` has {} elements, expected {} to match `{}`", argument, got, expected, reference)
+            }
+            Self::Overlap { first, second } => {
+                write!(f, "AccelerateError::Overlap - vforce received overlapping arrays: `{}` and `{}` share memory", first, second)
+            }
+            Self::DomainError { index, value } => {
+                write!(f, "AccelerateError::DomainError - vforce received an input outside the domain of the function: element {} is {}", index, value)
+            }
+            Self::NonFinite { index, value } => {
+                write!(f, "AccelerateError::NonFinite - vforce produced a non-finite result: element {} is {}", index, value)
+            }
+            Self::ArityMismatch { expected, got } => {
+                write!(f, "AccelerateError::ArityMismatch - vforce received the wrong number of input arrays: expected {}, got {}", expected, got)
             }
         }
     }
 }
 
+impl core::error::Error for AccelerateError {}
+
 mod sealed {
     pub trait Sealed {}
     impl Sealed for f32 {}
     impl Sealed for f64 {}
+    impl Sealed for bool {}
+    impl Sealed for u64 {}
+    impl Sealed for super::AccelerateComplex<f32> {}
+    impl Sealed for super::AccelerateComplex<f64> {}
 }
 
 /// Ensures that all inputs to an accelerate function must be the same numeric type: either f64 or
 /// f32
-pub trait AccelerateFloat: sealed::Sealed + Copy {
+pub trait AccelerateFloat: sealed::Sealed + Copy + PartialOrd
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    /// Zero in the implementing precision
+    const ZERO: Self;
+    /// One in the implementing precision
+    const ONE: Self;
+    /// Negative infinity in the implementing precision
+    const NEG_INFINITY: Self;
+
+    /// Returns true if the value is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+    /// Returns true if the value is NaN
+    fn is_nan(self) -> bool;
+    /// Returns true if the value is subnormal in the implementing precision
+    fn is_subnormal(self) -> bool;
+    /// Returns true if the sign bit is set, including for -0.0 and NaNs with a negative sign
+    fn is_sign_negative(self) -> bool;
+    /// Returns the absolute value
+    fn abs(self) -> Self;
+    /// Returns the minimum of two values, ignoring NaN
+    fn min(self, other: Self) -> Self;
+    /// Returns the maximum of two values, ignoring NaN
+    fn max(self, other: Self) -> Self;
+    /// Computes self * a + b with a single rounding
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// Returns the square root, correctly rounded
+    fn sqrt(self) -> Self;
+    /// Rounds up to the nearest integer
+    fn ceil(self) -> Self;
+    /// Returns the cube root
+    fn cbrt(self) -> Self;
+    /// Computes sqrt(self^2 + other^2) without undue overflow or underflow
+    fn hypot(self, other: Self) -> Self;
+    /// Converts from a double precision float, rounding if necessary
+    fn from_f64(x: f64) -> Self;
+    /// Converts to a double precision float, which is always exact
+    fn to_f64(self) -> f64;
+
     // Binary operations (out, a, b, count)
     /// # Safety
     /// All inputs must point to valid arrays of floating-point numbers. All must be of the same
@@ -240,8 +337,28 @@ macro_rules! impl_accelerate_float {
      $log2:ident, $log10:ident, $logb:ident, $sin:ident, $sinpi:ident,
      $cos:ident, $cospi:ident, $tan:ident, $tanpi:ident, $asin:ident,
      $acos:ident, $atan:ident, $sinh:ident, $cosh:ident, $tanh:ident,
-     $asinh:ident, $acosh:ident, $atanh:ident, $sincos:ident, $cosisin:ident) => {
+     $asinh:ident, $acosh:ident, $atanh:ident, $sincos:ident, $cosisin:ident,
+     $fma:ident, $sqrt_scalar:ident, $ceil_scalar:ident, $cbrt:ident, $hypot:ident) => {
         impl AccelerateFloat for $ty {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const NEG_INFINITY: Self = <$ty>::NEG_INFINITY;
+
+            fn is_finite(self) -> bool { <$ty>::is_finite(self) }
+            fn is_nan(self) -> bool { <$ty>::is_nan(self) }
+            fn is_subnormal(self) -> bool { <$ty>::is_subnormal(self) }
+            fn is_sign_negative(self) -> bool { <$ty>::is_sign_negative(self) }
+            fn abs(self) -> Self { <$ty>::abs(self) }
+            fn min(self, other: Self) -> Self { <$ty>::min(self, other) }
+            fn max(self, other: Self) -> Self { <$ty>::max(self, other) }
+            fn mul_add(self, a: Self, b: Self) -> Self { unsafe { $fma(self, a, b) } }
+            fn sqrt(self) -> Self { unsafe { $sqrt_scalar(self) } }
+            fn ceil(self) -> Self { unsafe { $ceil_scalar(self) } }
+            fn cbrt(self) -> Self { unsafe { $cbrt(self) } }
+            fn hypot(self, other: Self) -> Self { unsafe { $hypot(self, other) } }
+            fn from_f64(x: f64) -> Self { x as $ty }
+            fn to_f64(self) -> f64 { self as f64 }
+
             unsafe fn accelerate_pow(out: *mut Self, base: *const Self, exp: *const Self, count: *const i32)
             { unsafe { $pow(out, exp, base, count) } }
             unsafe fn accelerate_div(out: *mut Self, n: *const Self, d: *const Self, count: *const i32)
@@ -332,7 +449,8 @@ impl_accelerate_float!(f64,
     vvceil, vvfloor, vvfabs, vvint, vvnint, vvrsqrt, vvsqrt, vvrec,
     vvexp, vvexp2, vvexpm1, vvlog, vvlog1p, vvlog2, vvlog10, vvlogb,
     vvsin, vvsinpi, vvcos, vvcospi, vvtan, vvtanpi, vvasin, vvacos, vvatan,
-    vvsinh, vvcosh, vvtanh, vvasinh, vvacosh, vvatanh, vvsincos, vvcosisin
+    vvsinh, vvcosh, vvtanh, vvasinh, vvacosh, vvatanh, vvsincos, vvcosisin,
+    fma, sqrt, ceil, cbrt, hypot
 );
 
 impl_accelerate_float!(f32,
@@ -340,15 +458,36 @@ impl_accelerate_float!(f32,
     vvceilf, vvfloorf, vvfabsf, vvintf, vvnintf, vvrsqrtf, vvsqrtf, vvrecf,
     vvexpf, vvexp2f, vvexpm1f, vvlogf, vvlog1pf, vvlog2f, vvlog10f, vvlogbf,
     vvsinf, vvsinpif, vvcosf, vvcospif, vvtanf, vvtanpif, vvasinf, vvacosf, vvatanf,
-    vvsinhf, vvcoshf, vvtanhf, vvasinhf, vvacoshf, vvatanhf, vvsincosf, vvcosisinf
+    vvsinhf, vvcoshf, vvtanhf, vvasinhf, vvacoshf, vvatanhf, vvsincosf, vvcosisinf,
+    fmaf, sqrtf, ceilf, cbrtf, hypotf
 );
 
+/// Checks that the named slices all have the same length as the first, reporting the names of
+/// the arguments on mismatch
+macro_rules! check_lengths {
+    ($reference:ident, $($argument:ident),+) => {
+        $crate::check_lengths(
+            (stringify!($reference), $reference.len()),
+            &[$((stringify!($argument), $argument.len())),+]
+        )
+    };
+}
+
+/// Checks that the named mask can hold one lane per element of the named slice
+macro_rules! check_mask_len {
+    ($reference:ident, $mask:ident) => {
+        $crate::mask::check_mask_len((stringify!($reference), $reference.len()), stringify!($mask), $mask)
+    };
+}
+
 macro_rules! binary_vforce_op {
     (
     $(#[$out_attr:meta])*
     $name:ident,
     $(#[$in_place_attr:meta])*
     $name_in_place:ident,
+    $(#[$in_place_rhs_attr:meta])*
+    $name_in_place_rhs:ident,
     $method:ident,
     $a_name:ident,
     $b_name:ident
@@ -357,7 +496,7 @@ macro_rules! binary_vforce_op {
         pub fn $name<AF: AccelerateFloat>(
             out: &mut [AF], $a_name: &[AF], $b_name: &[AF]
         ) -> Result<(), AccelerateError> {
-            check_lengths_2($a_name.len(), $b_name.len(), out.len())?;
+            check_lengths!($a_name, $b_name, out)?;
             for (out_chunk, (a_chunk, b_chunk)) in out.chunks_mut(CHUNK)
                 .zip($a_name.chunks(CHUNK).zip($b_name.chunks(CHUNK)))
             {
@@ -370,13 +509,24 @@ macro_rules! binary_vforce_op {
         pub fn $name_in_place<AF: AccelerateFloat>(
             $a_name: &mut [AF], $b_name: &[AF]
         ) -> Result<(), AccelerateError> {
-            check_lengths_1($a_name.len(), $b_name.len())?;
+            check_lengths!($a_name, $b_name)?;
             for (a_chunk, b_chunk) in $a_name.chunks_mut(CHUNK).zip($b_name.chunks(CHUNK)) {
                 let count = a_chunk.len() as i32;
                 unsafe { AF::$method(a_chunk.as_mut_ptr(), a_chunk.as_ptr(), b_chunk.as_ptr(), &count); }
             }
             Ok(())
         }
+        $(#[$in_place_rhs_attr])*
+        pub fn $name_in_place_rhs<AF: AccelerateFloat>(
+            $a_name: &[AF], $b_name: &mut [AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name)?;
+            for (a_chunk, b_chunk) in $a_name.chunks(CHUNK).zip($b_name.chunks_mut(CHUNK)) {
+                let count = b_chunk.len() as i32;
+                unsafe { AF::$method(b_chunk.as_mut_ptr(), a_chunk.as_ptr(), b_chunk.as_ptr(), &count); }
+            }
+            Ok(())
+        }
     };
 }
 
@@ -386,6 +536,8 @@ macro_rules! unary_vforce_op {
     $name:ident,
     $(#[$in_place_attr:meta])*
     $name_in_place:ident,
+    $(#[$masked_attr:meta])*
+    $name_masked:ident,
     $method:ident,
     $input_name:ident
     ) => {
@@ -393,7 +545,7 @@ macro_rules! unary_vforce_op {
         pub fn $name<AF: AccelerateFloat>(
             out: &mut [AF], $input_name: &[AF]
         ) -> Result<(), AccelerateError> {
-            check_lengths_1($input_name.len(), out.len())?;
+            check_lengths!($input_name, out)?;
             for (out_chunk, in_chunk) in out.chunks_mut(CHUNK).zip($input_name.chunks(CHUNK)) {
                 let count = out_chunk.len() as i32;
                 unsafe { AF::$method(out_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count); }
@@ -409,33 +561,294 @@ macro_rules! unary_vforce_op {
                 unsafe { AF::$method(chunk.as_mut_ptr(), chunk.as_ptr(), &count); }
             }
         }
+        $(#[$masked_attr])*
+        pub fn $name_masked<AF: AccelerateFloat, M: $crate::mask::MaskElement>(
+            out: &mut [AF], $input_name: &[AF], mask: &[M]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($input_name, out)?;
+            check_mask_len!($input_name, mask)?;
+            // every lane of a tile is computed, so the kernel runs without branching, and only
+            // the selected results are kept
+            let mut buf = [AF::ZERO; TILE];
+            for (t, (out_chunk, in_chunk)) in out.chunks_mut(TILE).zip($input_name.chunks(TILE)).enumerate() {
+                let tile = &mut buf[..in_chunk.len()];
+                let count = in_chunk.len() as i32;
+                unsafe { AF::$method(tile.as_mut_ptr(), in_chunk.as_ptr(), &count); }
+                for (i, (o, &r)) in out_chunk.iter_mut().zip(tile.iter()).enumerate() {
+                    if M::lane(mask, t * TILE + i) {
+                        *o = r;
+                    }
+                }
+            }
+            Ok(())
+        }
+    };
+}
+
+macro_rules! unary_elementwise_op {
+    (
+    $(#[$out_attr:meta])*
+    $name:ident,
+    $(#[$in_place_attr:meta])*
+    $name_in_place:ident,
+    $(#[$masked_attr:meta])*
+    $name_masked:ident,
+    |$x:ident| $body:expr
+    ) => {
+        $(#[$out_attr])*
+        pub fn $name<AF: AccelerateFloat>(
+            out: &mut [AF], input: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!(input, out)?;
+            for (o, &$x) in out.iter_mut().zip(input) {
+                *o = $body;
+            }
+            Ok(())
+        }
+        $(#[$in_place_attr])*
+        pub fn $name_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+            for o in input.iter_mut() {
+                let $x = *o;
+                *o = $body;
+            }
+        }
+        $(#[$masked_attr])*
+        pub fn $name_masked<AF: AccelerateFloat, M: $crate::mask::MaskElement>(
+            out: &mut [AF], input: &[AF], mask: &[M]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!(input, out)?;
+            check_mask_len!(input, mask)?;
+            for (i, (o, &$x)) in out.iter_mut().zip(input).enumerate() {
+                if M::lane(mask, i) {
+                    *o = $body;
+                }
+            }
+            Ok(())
+        }
+    };
+}
+
+macro_rules! binary_elementwise_op {
+    (
+    $(#[$out_attr:meta])*
+    $name:ident,
+    $(#[$in_place_attr:meta])*
+    $name_in_place:ident,
+    $(#[$scalar_attr:meta])*
+    $name_scalar:ident,
+    $(#[$scalar_in_place_attr:meta])*
+    $name_scalar_in_place:ident,
+    |$a_name:ident, $b_name:ident| $body:expr
+    ) => {
+        $(#[$out_attr])*
+        pub fn $name<AF: AccelerateFloat>(
+            out: &mut [AF], $a_name: &[AF], $b_name: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name, out)?;
+            for (o, (&$a_name, &$b_name)) in out.iter_mut().zip($a_name.iter().zip($b_name)) {
+                *o = $body;
+            }
+            Ok(())
+        }
+        $(#[$in_place_attr])*
+        pub fn $name_in_place<AF: AccelerateFloat>(
+            $a_name: &mut [AF], $b_name: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name)?;
+            for (o, &$b_name) in $a_name.iter_mut().zip($b_name) {
+                let $a_name = *o;
+                *o = $body;
+            }
+            Ok(())
+        }
+        $(#[$scalar_attr])*
+        pub fn $name_scalar<AF: AccelerateFloat>(
+            out: &mut [AF], $a_name: &[AF], $b_name: AF
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, out)?;
+            for (o, &$a_name) in out.iter_mut().zip($a_name) {
+                *o = $body;
+            }
+            Ok(())
+        }
+        $(#[$scalar_in_place_attr])*
+        pub fn $name_scalar_in_place<AF: AccelerateFloat>(
+            $a_name: &mut [AF], $b_name: AF
+        ) {
+            for o in $a_name.iter_mut() {
+                let $a_name = *o;
+                *o = $body;
+            }
+        }
+    };
+}
+
+macro_rules! ternary_elementwise_op {
+    (
+    $(#[$out_attr:meta])*
+    $name:ident,
+    $(#[$in_place_attr:meta])*
+    $name_in_place:ident,
+    |$a_name:ident, $b_name:ident, $c_name:ident| $body:expr
+    ) => {
+        $(#[$out_attr])*
+        pub fn $name<AF: AccelerateFloat>(
+            out: &mut [AF], $a_name: &[AF], $b_name: &[AF], $c_name: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name, $c_name, out)?;
+            for (o, (&$a_name, (&$b_name, &$c_name))) in out.iter_mut()
+                .zip($a_name.iter().zip($b_name.iter().zip($c_name)))
+            {
+                *o = $body;
+            }
+            Ok(())
+        }
+        $(#[$in_place_attr])*
+        pub fn $name_in_place<AF: AccelerateFloat>(
+            $a_name: &mut [AF], $b_name: &[AF], $c_name: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name, $c_name)?;
+            for (o, (&$b_name, &$c_name)) in $a_name.iter_mut().zip($b_name.iter().zip($c_name)) {
+                let $a_name = *o;
+                *o = $body;
+            }
+            Ok(())
+        }
+    };
+}
+
+macro_rules! checked_unary_op {
+    (
+    $(#[$out_attr:meta])*
+    $name:ident,
+    $(#[$in_place_attr:meta])*
+    $name_in_place:ident,
+    $unchecked:ident,
+    $unchecked_in_place:ident,
+    $domain:expr
+    ) => {
+        $(#[$out_attr])*
+        pub fn $name<AF: AccelerateFloat>(
+            out: &mut [AF], input: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!(input, out)?;
+            $domain.check(input)?;
+            $unchecked(out, input)
+        }
+        $(#[$in_place_attr])*
+        pub fn $name_in_place<AF: AccelerateFloat>(
+            input: &mut [AF]
+        ) -> Result<(), AccelerateError> {
+            $domain.check(input)?;
+            $unchecked_in_place(input);
+            Ok(())
+        }
+    };
+}
+
+macro_rules! checked_binary_op {
+    (
+    $(#[$out_attr:meta])*
+    $name:ident,
+    $(#[$in_place_attr:meta])*
+    $name_in_place:ident,
+    $(#[$in_place_rhs_attr:meta])*
+    $name_in_place_rhs:ident,
+    $unchecked:ident,
+    $unchecked_in_place:ident,
+    $unchecked_in_place_rhs:ident,
+    $a_name:ident,
+    $b_name:ident,
+    $domain:expr
+    ) => {
+        $(#[$out_attr])*
+        pub fn $name<AF: AccelerateFloat>(
+            out: &mut [AF], $a_name: &[AF], $b_name: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name, out)?;
+            $domain.check($a_name, $b_name)?;
+            $unchecked(out, $a_name, $b_name)
+        }
+        $(#[$in_place_attr])*
+        pub fn $name_in_place<AF: AccelerateFloat>(
+            $a_name: &mut [AF], $b_name: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name)?;
+            $domain.check($a_name, $b_name)?;
+            $unchecked_in_place($a_name, $b_name)
+        }
+        $(#[$in_place_rhs_attr])*
+        pub fn $name_in_place_rhs<AF: AccelerateFloat>(
+            $a_name: &[AF], $b_name: &mut [AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!($a_name, $b_name)?;
+            $domain.check($a_name, $b_name)?;
+            $unchecked_in_place_rhs($a_name, $b_name)
+        }
     };
 }
 
 pub(crate) const CHUNK: usize = i32::MAX as usize;
 
-pub(crate) fn check_lengths_1(a: usize, b: usize) -> Result<(), AccelerateError> {
-    if a != b {
-        return Err(AccelerateError::LengthMismatch { expected: a, got: b });
-    }
-    Ok(())
+/// Size of the stack buffers used by functions which need scratch space for intermediate results
+pub(crate) const TILE: usize = 256;
+
+/// Applies a unary kernel to a single value
+pub(crate) fn scalar_op<AF: AccelerateFloat>(
+    method: unsafe fn(*mut AF, *const AF, *const i32), x: AF
+) -> AF {
+    let mut out = x;
+    unsafe { method(&mut out, &x, &1); }
+    out
 }
-pub(crate) fn check_lengths_2(a: usize, b: usize, c: usize) -> Result<(), AccelerateError> {
-    if a != b {
-        return Err(AccelerateError::LengthMismatch { expected: a, got: b });
-    }
-    if a != c {
-        return Err(AccelerateError::LengthMismatch { expected: a, got: c });
+
+/// Checks that each of `others` has the same length as `reference`, where each argument is given
+/// as its name and length
+pub(crate) fn check_lengths(
+    reference: (&'static str, usize), others: &[(&'static str, usize)]
+) -> Result<(), AccelerateError> {
+    for &(argument, got) in others {
+        if got != reference.1 {
+            return Err(AccelerateError::LengthMismatch {
+                argument, reference: reference.0, expected: reference.1, got
+            });
+        }
     }
     Ok(())
 }
 
+/// Discards the result of an array function whose only error is a length mismatch, for arrays
+/// whose lengths are known to match
+pub(crate) fn infallible(result: Result<(), AccelerateError>) {
+    debug_assert!(result.is_ok(), "arrays of matching lengths cannot mismatch");
+}
+
 pub mod arithmetic;
 pub mod exponential;
 pub mod trig;
 pub mod hyperbolic;
-
-#[cfg(test)]
+pub mod mask;
+pub mod domain;
+pub mod summary;
+pub mod fixed;
+pub mod raw;
+pub mod op;
+pub mod stream;
+pub mod poly;
+pub mod space;
+pub mod window;
+#[cfg(feature = "alloc")]
+pub mod fft;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "npy")]
+pub mod npy;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
 
 #[cfg(test)]
@@ -445,6 +858,15 @@ mod tests {
     use super::exponential::*;
     use super::trig::*;
     use super::hyperbolic::*;
+    use super::mask::*;
+    use super::domain::*;
+    use super::summary::*;
+    use super::fixed::*;
+    use super::op::*;
+    use super::stream::VfMapExt;
+    use super::poly::*;
+    use super::space::*;
+    use super::window::*;
     use alloc::vec;
     use alloc::vec::Vec;
 
@@ -540,6 +962,43 @@ mod tests {
         check_binary_in_place(pow_array_in_place, f64::powf, &bases, &exponents, "pow_array_in_place");
     }
 
+    #[test]
+    fn test_powi_array() {
+        let inputs = [2.0, -3.0, 0.5, 10.0];
+        for n in [0, 1, 3, -2, 7] {
+            let mut out = [0.0f64; 4];
+            powi_array(&mut out, &inputs, n).unwrap();
+            let expected: Vec<f64> = inputs.iter().map(|&x| x.powi(n)).collect();
+            assert_approx(&out, &expected, 1e-9, "powi_array");
+            let mut buf = inputs;
+            powi_array_in_place(&mut buf, n);
+            assert_eq!(buf, out);
+        }
+    }
+
+    #[test]
+    fn test_cbrt_array() {
+        let inputs = [27.0, -8.0, 0.001, 2.0];
+        check_unary(cbrt_array, f64::cbrt, &inputs, "cbrt_array");
+        check_unary_in_place(cbrt_array_in_place, f64::cbrt, &inputs, "cbrt_array_in_place");
+    }
+
+    #[test]
+    fn test_hypot_array() {
+        let x = [3.0, -5.0, 1e300, 0.0];
+        let y = [4.0, 12.0, 1e300, -2.0];
+        let mut out = [0.0f64; 4];
+        hypot_array(&mut out, &x, &y).unwrap();
+        assert_eq!([out[0], out[1], out[3]], [5.0, 13.0, 2.0]);
+        // no overflow for large inputs
+        assert!((out[2] / 1e300 - 2.0f64.sqrt()).abs() < 1e-15);
+        check_binary_in_place(hypot_array_in_place, f64::hypot, &x[..2], &y[..2], "hypot_array_in_place");
+        check_scalar(hypot_scalar_array, hypot_scalar_array_in_place, f64::hypot, &x[..2], 4.0, "hypot_scalar_array");
+        check_binary(rhypot_array, |x, y| 1.0 / x.hypot(y), &x[..2], &y[..2], "rhypot_array");
+        check_binary_in_place(rhypot_array_in_place, |x, y| 1.0 / x.hypot(y), &x[..2], &y[..2], "rhypot_array_in_place");
+        check_scalar(rhypot_scalar_array, rhypot_scalar_array_in_place, |x, y| 1.0 / x.hypot(y), &x[..2], 4.0, "rhypot_scalar_array");
+    }
+
     // ── Arithmetic and Auxiliary functions ──
 
     #[test]
@@ -607,9 +1066,67 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "stub: vvnint rounding mode for ties may differ from f64::round"]
     fn test_nint_array() {
-        todo!("vvnint may round ties to even vs f64::round which rounds ties away from zero");
+        let inputs = [0.5, 1.5, 2.5, -0.5, -2.5, 2.4, -3.7];
+        check_unary(nint_array, f64::round_ties_even, &inputs, "nint_array");
+        check_unary_in_place(nint_array_in_place, f64::round_ties_even, &inputs, "nint_array_in_place");
+    }
+
+    // ── Rounding ──
+
+    const TIES: [f64; 10] = [0.5, 1.5, 2.5, -0.5, -1.5, -2.5, 0.49999999999999994, 2.4, -3.7, 4503599627370497.0];
+
+    #[test]
+    fn test_round_half_away_array() {
+        check_unary(round_half_away_array, f64::round, &TIES, "round_half_away_array");
+        check_unary_in_place(round_half_away_array_in_place, f64::round, &TIES, "round_half_away_array_in_place");
+        let mut out = [0.0f32; 3];
+        round_half_away_array(&mut out, &[0.5f32, -2.5, 8388609.0]).unwrap();
+        assert_eq!(out, [1.0, -3.0, 8388609.0]);
+    }
+
+    #[test]
+    fn test_round_half_even_array() {
+        check_unary(round_half_even_array, f64::round_ties_even, &TIES, "round_half_even_array");
+        check_unary_in_place(round_half_even_array_in_place, f64::round_ties_even, &TIES, "round_half_even_array_in_place");
+        check_unary(rint_array, f64::round_ties_even, &TIES, "rint_array");
+        check_unary_in_place(rint_array_in_place, f64::round_ties_even, &TIES, "rint_array_in_place");
+
+        // every backend agrees on the sign of zero
+        let mut out = [0.0f64; 2];
+        round_half_even_array(&mut out, &[-0.5, -0.0]).unwrap();
+        assert!(out.iter().all(|x| *x == 0.0 && x.is_sign_negative()));
+        let mut out = [0.0f32; 3];
+        round_half_even_array(&mut out, &[0.5f32, -2.5, 8388609.0]).unwrap();
+        assert_eq!(out, [0.0, -2.0, 8388609.0]);
+    }
+
+    #[test]
+    fn test_round_to_multiple_array() {
+        let inputs = [7.0, -7.0, 5.0, 1.3, 1e300];
+        let mut out = [0.0f64; 5];
+        round_to_multiple_array(&mut out, &inputs, 2.0).unwrap();
+        assert_eq!(out, [8.0, -8.0, 4.0, 2.0, 1e300]);
+        let mut buf = inputs;
+        round_to_multiple_array_in_place(&mut buf, 0.25);
+        assert_eq!(buf, [7.0, -7.0, 5.0, 1.25, 1e300]);
+    }
+
+    #[test]
+    fn test_round_to_decimals_array() {
+        let inputs = [1.23456, 1234.5, -0.125, 0.375, 1e300];
+        let mut out = [0.0f64; 5];
+        round_to_decimals_array(&mut out, &inputs, 2).unwrap();
+        assert_eq!(out, [1.23, 1234.5, -0.12, 0.38, 1e300]);
+        round_to_decimals_array(&mut out, &inputs, -2).unwrap();
+        assert_eq!(out, [0.0, 1200.0, -0.0, 0.0, 1e300]);
+        round_to_decimals_array(&mut out, &inputs, 400).unwrap();
+        assert_eq!(out, inputs);
+        round_to_decimals_array(&mut out, &inputs, -400).unwrap();
+        assert_eq!(out, [0.0, 0.0, -0.0, 0.0, 0.0]);
+        let mut buf = inputs;
+        round_to_decimals_array_in_place(&mut buf, 0);
+        assert_eq!(buf, [1.0, 1234.0, -0.0, 0.0, 1e300]);
     }
 
     #[test]
@@ -630,6 +1147,210 @@ mod tests {
         check_unary_in_place(rec_array_in_place, |x| 1.0 / x, &INPUTS, "rec_array_in_place");
     }
 
+    // ── Elementwise arithmetic ──
+
+    // Helper to test a scalar-broadcast out-of-place and in-place function pair against a scalar reference
+    #[allow(clippy::type_complexity)]
+    fn check_scalar(
+        vforce_fn: fn(&mut [f64], &[f64], f64) -> Result<(), AccelerateError>,
+        vforce_fn_in_place: fn(&mut [f64], f64),
+        scalar_fn: fn(f64, f64) -> f64,
+        a: &[f64],
+        b: f64,
+        name: &str,
+    ) {
+        let expected: Vec<f64> = a.iter().map(|&x| scalar_fn(x, b)).collect();
+        let mut out = vec![0.0f64; a.len()];
+        vforce_fn(&mut out, a, b).unwrap();
+        assert_approx(&out, &expected, 1e-12, name);
+        let mut buf = a.to_vec();
+        vforce_fn_in_place(&mut buf, b);
+        assert_approx(&buf, &expected, 1e-12, name);
+    }
+
+    #[test]
+    fn test_add_sub_mul_array() {
+        let a = [1.5, -2.0, 3.25, 0.0];
+        let b = [0.5, 4.0, -1.25, 7.0];
+        check_binary(add_array, |a, b| a + b, &a, &b, "add_array");
+        check_binary_in_place(add_array_in_place, |a, b| a + b, &a, &b, "add_array_in_place");
+        check_scalar(add_scalar_array, add_scalar_array_in_place, |a, b| a + b, &a, 2.5, "add_scalar_array");
+        check_binary(sub_array, |a, b| a - b, &a, &b, "sub_array");
+        check_binary_in_place(sub_array_in_place, |a, b| a - b, &a, &b, "sub_array_in_place");
+        check_scalar(sub_scalar_array, sub_scalar_array_in_place, |a, b| a - b, &a, 2.5, "sub_scalar_array");
+        check_binary(mul_array, |a, b| a * b, &a, &b, "mul_array");
+        check_binary_in_place(mul_array_in_place, |a, b| a * b, &a, &b, "mul_array_in_place");
+        check_scalar(mul_scalar_array, mul_scalar_array_in_place, |a, b| a * b, &a, 2.5, "mul_scalar_array");
+    }
+
+    #[test]
+    fn test_abs_diff_min_max_array() {
+        let a = [1.5, -2.0, 3.25, f64::NAN];
+        let b = [0.5, 4.0, -1.25, 7.0];
+        check_binary(min_array, f64::min, &a, &b, "min_array");
+        check_binary_in_place(max_array_in_place, f64::max, &a, &b, "max_array_in_place");
+        check_scalar(max_scalar_array, max_scalar_array_in_place, f64::max, &a, 1.0, "max_scalar_array");
+        check_scalar(min_scalar_array, min_scalar_array_in_place, f64::min, &a, 1.0, "min_scalar_array");
+
+        let a = [1.5, -2.0, 3.25, 0.0];
+        check_binary(abs_diff_array, |a, b| (a - b).abs(), &a, &b, "abs_diff_array");
+        check_binary_in_place(abs_diff_array_in_place, |a, b| (a - b).abs(), &a, &b, "abs_diff_array_in_place");
+        check_scalar(abs_diff_scalar_array, abs_diff_scalar_array_in_place, |a, b| (a - b).abs(), &a, 1.0, "abs_diff_scalar_array");
+    }
+
+    #[test]
+    fn test_neg_sqr_array() {
+        check_unary(neg_array, |x| -x, &INPUTS, "neg_array");
+        check_unary_in_place(neg_array_in_place, |x| -x, &INPUTS, "neg_array_in_place");
+        check_unary(sqr_array, |x| x * x, &INPUTS, "sqr_array");
+        check_unary_in_place(sqr_array_in_place, |x| x * x, &INPUTS, "sqr_array_in_place");
+    }
+
+    #[test]
+    fn test_fma_array() {
+        let a = [2.0, 1.0 + 2.0f64.powi(-30), -3.0];
+        let b = [3.0, 1.0 - 2.0f64.powi(-30), 0.5];
+        let c = [1.0, -1.0, 4.0];
+        // the middle case is only nonzero if the multiply-add is fused
+        let expected = [7.0, -(2.0f64.powi(-60)), 2.5];
+        let mut out = [0.0f64; 3];
+        fma_array(&mut out, &a, &b, &c).unwrap();
+        assert_eq!(out, expected);
+        let mut buf = a;
+        fma_array_in_place(&mut buf, &b, &c).unwrap();
+        assert_eq!(buf, expected);
+
+        fma_scalar_array(&mut out, &a[..], 2.0, 1.0).unwrap();
+        assert_approx(&out, &[5.0, 3.0 + 2.0f64.powi(-29), -5.0], 1e-15, "fma_scalar_array");
+        let mut buf = a;
+        fma_scalar_array_in_place(&mut buf, 2.0, 1.0);
+        assert_eq!(buf, out);
+
+        let result = fma_array(&mut out, &a, &b, &c[..2]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_clamp_array() {
+        let input = [-5.0, 0.5, 5.0, f64::NAN];
+        let lo = [-1.0, 0.0, 0.0, 0.0];
+        let hi = [1.0, 1.0, 2.0, 1.0];
+        let mut out = [0.0f64; 4];
+        clamp_array(&mut out, &input, &lo, &hi).unwrap();
+        assert_eq!(&out[..3], &[-1.0, 0.5, 2.0]);
+        assert!(out[3].is_nan());
+        let mut buf = input;
+        clamp_array_in_place(&mut buf, &lo, &hi).unwrap();
+        assert_eq!(&buf[..3], &[-1.0, 0.5, 2.0]);
+
+        clamp_scalar_array(&mut out, &input, 0.0, 1.0).unwrap();
+        assert_eq!(&out[..3], &[0.0, 0.5, 1.0]);
+        let mut buf = input;
+        clamp_scalar_array_in_place(&mut buf, 0.0, 1.0);
+        assert_eq!(&buf[..3], &[0.0, 0.5, 1.0]);
+        assert!(buf[3].is_nan());
+    }
+
+    #[test]
+    fn test_lerp_array() {
+        let a = [0.0, 1.0, -2.0, 0.1];
+        let b = [10.0, 3.0, 2.0, 0.7];
+        let t = [0.5, 0.0, 0.25, 1.0];
+        let mut out = [0.0f64; 4];
+        lerp_array(&mut out, &a, &b, &t).unwrap();
+        // the endpoints are exact
+        assert_eq!(out, [5.0, 1.0, -1.0, 0.7]);
+        let mut buf = a;
+        lerp_array_in_place(&mut buf, &b, &t).unwrap();
+        assert_eq!(buf, out);
+
+        lerp_scalar_array(&mut out, &a, &b, 0.5).unwrap();
+        assert_approx(&out, &[5.0, 2.0, 0.0, 0.4], 1e-15, "lerp_scalar_array");
+        let mut buf = a;
+        lerp_scalar_array_in_place(&mut buf, &b, 0.5).unwrap();
+        assert_eq!(buf, out);
+    }
+
+    // ── Float decomposition ──
+
+    #[test]
+    fn test_frexp_ldexp_array() {
+        let inputs = [8.0, -0.375, 1.0, 0.0, f64::MIN_POSITIVE / 8.0, f64::MAX, f64::INFINITY];
+        let mut mantissas = [0.0f64; 7];
+        let mut exponents = [0i32; 7];
+        frexp_array(&mut mantissas, &mut exponents, &inputs).unwrap();
+        assert_eq!(mantissas, [0.5, -0.75, 0.5, 0.0, 0.5, 1.0 - f64::EPSILON / 2.0, f64::INFINITY]);
+        assert_eq!(exponents, [4, -1, 1, 0, -1024, 1024, 0]);
+
+        let mut buf = inputs;
+        let mut buf_exponents = [0i32; 7];
+        frexp_array_in_place(&mut buf_exponents, &mut buf).unwrap();
+        assert_eq!(buf, mantissas);
+        assert_eq!(buf_exponents, exponents);
+
+        let mut out = [0.0f64; 7];
+        ldexp_array(&mut out, &mantissas, &exponents).unwrap();
+        assert_eq!(out, inputs);
+        ldexp_array_in_place(&mut buf, &exponents).unwrap();
+        assert_eq!(buf, inputs);
+
+        // f32 subnormals are recovered exactly too
+        let small = [f32::MIN_POSITIVE / 4.0, 3.0];
+        let mut m = [0.0f32; 2];
+        let mut e = [0i32; 2];
+        frexp_array(&mut m, &mut e, &small).unwrap();
+        assert_eq!((m, e), ([0.5, 0.75], [-127, 2]));
+    }
+
+    #[test]
+    fn test_scalbn_array() {
+        let inputs = [1.0, -3.0, 0.75];
+        let mut out = [0.0f64; 3];
+        scalbn_array(&mut out, &inputs, 10).unwrap();
+        assert_eq!(out, [1024.0, -3072.0, 768.0]);
+        let mut buf = [f64::MAX];
+        scalbn_array_in_place(&mut buf, -2000);
+        scalbn_array_in_place(&mut buf, 1000);
+        assert_eq!(buf, [f64::MAX / 2f64.powi(1000)]);
+        let mut buf = [1.0f64];
+        scalbn_array_in_place(&mut buf, -1074);
+        assert_eq!(buf, [f64::from_bits(1)]);
+        let mut buf = [1.0f64];
+        scalbn_array_in_place(&mut buf, 1024);
+        assert_eq!(buf, [f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_ilogb_array() {
+        let inputs = [1.0, 0.75, -1024.0, f64::from_bits(1), 0.0, f64::NAN, f64::NEG_INFINITY];
+        let mut out = [0i32; 7];
+        ilogb_array(&mut out, &inputs).unwrap();
+        assert_eq!(out, [0, -1, 10, -1074, i32::MIN, i32::MIN, i32::MAX]);
+    }
+
+    #[test]
+    fn test_modf_array() {
+        let inputs = [3.75, -2.5, -4.0, 0.25, f64::NEG_INFINITY];
+        let expected_int = [3.0, -2.0, -4.0, 0.0, f64::NEG_INFINITY];
+        let expected_frac = [0.75, -0.5, -0.0, 0.25, -0.0];
+        let mut int_out = [0.0f64; 5];
+        let mut frac_out = [0.0f64; 5];
+        modf_array(&mut int_out, &mut frac_out, &inputs).unwrap();
+        assert_eq!(int_out, expected_int);
+        assert_eq!(frac_out, expected_frac);
+        // the fractional part keeps the sign of the input
+        assert!(frac_out[2].is_sign_negative());
+
+        let mut buf = inputs;
+        modf_array_in_place_int(&mut frac_out, &mut buf).unwrap();
+        assert_eq!(buf, expected_int);
+        assert_eq!(frac_out, expected_frac);
+        let mut buf = inputs;
+        modf_array_in_place_frac(&mut int_out, &mut buf).unwrap();
+        assert_eq!(int_out, expected_int);
+        assert_eq!(buf, expected_frac);
+    }
+
     // ── Exponential and Logarithmic functions ──
 
     #[test]
@@ -680,6 +1401,69 @@ mod tests {
         todo!("logb extracts the exponent as a float; no direct core equivalent");
     }
 
+    #[test]
+    fn test_exp10_array() {
+        let inputs = [0.0, 1.0, 2.0, -3.0, 0.5];
+        check_unary(exp10_array, |x| 10f64.powf(x), &inputs, "exp10_array");
+        check_unary_in_place(exp10_array_in_place, |x| 10f64.powf(x), &inputs, "exp10_array_in_place");
+    }
+
+    #[test]
+    fn test_exp2m1_array() {
+        let inputs = [1e-12, -3e-9, 0.25, 3.0, -4.0];
+        let expected = [
+            (1e-12 * core::f64::consts::LN_2).exp_m1(),
+            (-3e-9 * core::f64::consts::LN_2).exp_m1(),
+            2f64.powf(0.25) - 1.0,
+            7.0,
+            -0.9375,
+        ];
+        let mut out = [0.0f64; 5];
+        exp2m1_array(&mut out, &inputs).unwrap();
+        for (i, (&a, &e)) in out.iter().zip(expected.iter()).enumerate() {
+            assert!(((a - e) / e).abs() < 1e-14, "exp2m1_array[{i}]: got {a}, expected {e}");
+        }
+        let mut buf = inputs;
+        exp2m1_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+    }
+
+    #[test]
+    fn test_exp10m1_array() {
+        let inputs = [1e-12, -3e-9, 0.25, 2.0, -1.0];
+        let expected = [
+            (1e-12 * core::f64::consts::LN_10).exp_m1(),
+            (-3e-9 * core::f64::consts::LN_10).exp_m1(),
+            10f64.powf(0.25) - 1.0,
+            99.0,
+            -0.9,
+        ];
+        let mut out = [0.0f64; 5];
+        exp10m1_array(&mut out, &inputs).unwrap();
+        for (i, (&a, &e)) in out.iter().zip(expected.iter()).enumerate() {
+            assert!(((a - e) / e).abs() < 1e-14, "exp10m1_array[{i}]: got {a}, expected {e}");
+        }
+        let mut buf = inputs;
+        exp10m1_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+    }
+
+    #[test]
+    fn test_logn_array() {
+        let inputs = [1.0, 8.0, 1000.0, 0.5];
+        let mut out = [0.0f64; 4];
+        logn_array(&mut out, &inputs, 2.0).unwrap();
+        assert_eq!(out, [0.0, 3.0, 1000f64.log2(), -1.0]);
+        logn_array(&mut out, &inputs, 10.0).unwrap();
+        assert_eq!(out, [0.0, 8f64.log10(), 3.0, 0.5f64.log10()]);
+        logn_array(&mut out, &inputs, 3.0).unwrap();
+        let expected: Vec<f64> = inputs.iter().map(|&x| x.log(3.0)).collect();
+        assert_approx(&out, &expected, 1e-12, "logn_array");
+        let mut buf = inputs;
+        logn_array_in_place(&mut buf, 3.0);
+        assert_eq!(buf, out);
+    }
+
     // ── Trigonometric functions ──
 
     #[test]
@@ -797,6 +1581,987 @@ mod tests {
         assert_approx(&cos_out, &expected_cos, 1e-10, "sincos_array (cos)");
     }
 
+    #[test]
+    fn test_sincospi_array() {
+        let inputs = [0.0, 0.5, 1.0, 0.25, -1.5];
+        let expected_sin = [0.0, 1.0, 0.0, core::f64::consts::FRAC_1_SQRT_2, 1.0];
+        let expected_cos = [1.0, 0.0, -1.0, core::f64::consts::FRAC_1_SQRT_2, 0.0];
+        let mut sin_out = [0.0f64; 5];
+        let mut cos_out = [0.0f64; 5];
+        sincospi_array(&mut sin_out, &mut cos_out, &inputs).unwrap();
+        assert_approx(&sin_out, &expected_sin, 1e-15, "sincospi_array (sin)");
+        assert_approx(&cos_out, &expected_cos, 1e-15, "sincospi_array (cos)");
+
+        let mut buf = inputs;
+        sincospi_array_in_place_sin(&mut cos_out, &mut buf).unwrap();
+        assert_approx(&buf, &expected_sin, 1e-15, "sincospi_array_in_place_sin (sin)");
+        assert_approx(&cos_out, &expected_cos, 1e-15, "sincospi_array_in_place_sin (cos)");
+        let mut buf = inputs;
+        sincospi_array_in_place_cos(&mut sin_out, &mut buf).unwrap();
+        assert_approx(&sin_out, &expected_sin, 1e-15, "sincospi_array_in_place_cos (sin)");
+        assert_approx(&buf, &expected_cos, 1e-15, "sincospi_array_in_place_cos (cos)");
+    }
+
+    // ── Degree-based trigonometry ──
+
+    const DEGREES: [f64; 12] = [0.0, 30.0, 60.0, 90.0, 120.0, 150.0, 180.0, 210.0, 270.0, -30.0, 390.0, -720.0];
+
+    #[test]
+    fn test_sind_cosd_array() {
+        let expected_sin = [0.0, 0.5, 0.75f64.sqrt(), 1.0, 0.75f64.sqrt(), 0.5, 0.0, -0.5, -1.0, -0.5, 0.5, 0.0];
+        let expected_cos = [1.0, 0.75f64.sqrt(), 0.5, 0.0, -0.5, -(0.75f64.sqrt()), -1.0, -(0.75f64.sqrt()), 0.0, 0.75f64.sqrt(), 0.75f64.sqrt(), 1.0];
+        let mut out = [0.0f64; 12];
+        sind_array(&mut out, &DEGREES).unwrap();
+        assert_approx(&out, &expected_sin, 1e-15, "sind_array");
+        // multiples of 30 and 90 are exact
+        for i in [0, 1, 3, 5, 6, 7, 8, 9, 10, 11] {
+            assert_eq!(out[i], expected_sin[i], "sind_array[{i}]");
+        }
+        let mut buf = DEGREES;
+        sind_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+
+        cosd_array(&mut out, &DEGREES).unwrap();
+        assert_approx(&out, &expected_cos, 1e-15, "cosd_array");
+        for i in [0, 2, 3, 4, 6, 8, 11] {
+            assert_eq!(out[i], expected_cos[i], "cosd_array[{i}]");
+        }
+        let mut buf = DEGREES;
+        cosd_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+
+        let inputs = [1.0, 37.5, -123.4, 1e5 + 0.3];
+        check_unary(sind_array, |x| x.to_radians().sin(), &inputs, "sind_array");
+        check_unary(cosd_array, |x| x.to_radians().cos(), &inputs, "cosd_array");
+    }
+
+    #[test]
+    fn test_tand_array() {
+        let inputs = [0.0, 45.0, -45.0, 135.0, 90.0, 10.0];
+        let mut out = [0.0f64; 6];
+        tand_array(&mut out, &inputs).unwrap();
+        assert_eq!(&out[..4], &[0.0, 1.0, -1.0, -1.0]);
+        assert!(out[4].is_infinite());
+        assert_approx(&out[5..], &[10f64.to_radians().tan()], 1e-15, "tand_array");
+        let mut buf = inputs;
+        tand_array_in_place(&mut buf);
+        assert_eq!(&buf[..4], &out[..4]);
+    }
+
+    #[test]
+    fn test_sincosd_array() {
+        let mut sin_out = [0.0f64; 12];
+        let mut cos_out = [0.0f64; 12];
+        sincosd_array(&mut sin_out, &mut cos_out, &DEGREES).unwrap();
+        let mut expected_sin = [0.0f64; 12];
+        let mut expected_cos = [0.0f64; 12];
+        sind_array(&mut expected_sin, &DEGREES).unwrap();
+        cosd_array(&mut expected_cos, &DEGREES).unwrap();
+        assert_eq!(sin_out, expected_sin);
+        assert_eq!(cos_out, expected_cos);
+
+        let mut buf = DEGREES;
+        sincosd_array_in_place_sin(&mut cos_out, &mut buf).unwrap();
+        assert_eq!(buf, expected_sin);
+        assert_eq!(cos_out, expected_cos);
+        let mut buf = DEGREES;
+        sincosd_array_in_place_cos(&mut sin_out, &mut buf).unwrap();
+        assert_eq!(sin_out, expected_sin);
+        assert_eq!(buf, expected_cos);
+    }
+
+    #[test]
+    fn test_inverse_degrees_array() {
+        let inputs = [0.0, 0.5, -0.5, 1.0, -1.0, 0.3];
+        let mut out = [0.0f64; 6];
+        asind_array(&mut out, &inputs).unwrap();
+        assert_eq!(&out[..5], &[0.0, 30.0, -30.0, 90.0, -90.0]);
+        assert_approx(&out[5..], &[0.3f64.asin().to_degrees()], 1e-12, "asind_array");
+        let mut buf = inputs;
+        asind_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+
+        acosd_array(&mut out, &inputs).unwrap();
+        assert_eq!(&out[..5], &[90.0, 60.0, 120.0, 0.0, 180.0]);
+        assert_approx(&out[5..], &[0.3f64.acos().to_degrees()], 1e-12, "acosd_array");
+        let mut buf = inputs;
+        acosd_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+
+        let inputs = [0.0, 1.0, -1.0, f64::INFINITY, f64::NEG_INFINITY, 0.3];
+        atand_array(&mut out, &inputs).unwrap();
+        assert_eq!(&out[..5], &[0.0, 45.0, -45.0, 90.0, -90.0]);
+        assert_approx(&out[5..], &[0.3f64.atan().to_degrees()], 1e-12, "atand_array");
+        let mut buf = inputs;
+        atand_array_in_place(&mut buf);
+        assert_eq!(buf, out);
+    }
+
+    #[test]
+    fn test_atan2d_array() {
+        let y = [1.0, 1.0, 0.0, -2.0, 0.0, 3.0];
+        let x = [1.0, -1.0, -5.0, 0.0, 1.0, 4.0];
+        let mut out = [0.0f64; 6];
+        atan2d_array(&mut out, &y, &x).unwrap();
+        assert_eq!(&out[..5], &[45.0, 135.0, 180.0, -90.0, 0.0]);
+        assert_approx(&out[5..], &[3f64.atan2(4.0).to_degrees()], 1e-12, "atan2d_array");
+        let mut buf = y;
+        atan2d_array_in_place(&mut buf, &x).unwrap();
+        assert_eq!(buf, out);
+    }
+
+    // ── Softmax and log-sum-exp ──
+
+    fn softmax_ref(input: &[f64]) -> Vec<f64> {
+        let max = input.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let sum: f64 = input.iter().map(|&x| (x - max).exp()).sum();
+        input.iter().map(|&x| (x - max).exp() / sum).collect()
+    }
+
+    #[test]
+    fn test_logsumexp() {
+        let expected = INPUTS.iter().map(|&x| x.exp()).sum::<f64>().ln();
+        assert_approx(&[logsumexp(&INPUTS)], &[expected], 1e-10, "logsumexp");
+        // would overflow without the max shift
+        let large = [1000.0, 1000.0, 999.0];
+        let expected = 1000.0 + (2.0 + (-1.0f64).exp()).ln();
+        assert_approx(&[logsumexp(&large)], &[expected], 1e-10, "logsumexp (large)");
+        assert_eq!(logsumexp::<f64>(&[]), f64::NEG_INFINITY);
+        assert_eq!(logsumexp(&[f64::NEG_INFINITY; 3]), f64::NEG_INFINITY);
+        // spans several stack tiles
+        let long: Vec<f64> = (0..1000).map(|i| (i as f64) * 0.01).collect();
+        let expected = long.iter().map(|&x| x.exp()).sum::<f64>().ln();
+        assert_approx(&[logsumexp(&long)], &[expected], 1e-9, "logsumexp (long)");
+    }
+
+    #[test]
+    fn test_softmax_array() {
+        let mut out = [0.0f64; 4];
+        softmax_array(&mut out, &INPUTS).unwrap();
+        assert_approx(&out, &softmax_ref(&INPUTS), 1e-12, "softmax_array");
+        let mut buf = INPUTS;
+        softmax_array_in_place(&mut buf);
+        assert_approx(&buf, &softmax_ref(&INPUTS), 1e-12, "softmax_array_in_place");
+
+        let large = [1000.0, 1001.0, 1002.0];
+        let mut out = [0.0f64; 3];
+        softmax_array(&mut out, &large).unwrap();
+        assert_approx(&out, &softmax_ref(&[0.0, 1.0, 2.0]), 1e-12, "softmax_array (large)");
+    }
+
+    #[test]
+    fn test_log_softmax_array() {
+        let expected: Vec<f64> = softmax_ref(&INPUTS).iter().map(|x| x.ln()).collect();
+        let mut out = [0.0f64; 4];
+        log_softmax_array(&mut out, &INPUTS).unwrap();
+        assert_approx(&out, &expected, 1e-12, "log_softmax_array");
+        let mut buf = INPUTS;
+        log_softmax_array_in_place(&mut buf);
+        assert_approx(&buf, &expected, 1e-12, "log_softmax_array_in_place");
+    }
+
+    #[test]
+    fn test_softmax_rows() {
+        let input = [0.5, 1.0, 2.0, 3.0, -1.0, 0.0];
+        let mut expected = softmax_ref(&input[..3]);
+        expected.extend(softmax_ref(&input[3..]));
+
+        let mut out = [0.0f64; 6];
+        softmax_rows(&mut out, &input, 2, 3).unwrap();
+        assert_approx(&out, &expected, 1e-12, "softmax_rows");
+        let mut buf = input;
+        softmax_rows_in_place(&mut buf, 2, 3).unwrap();
+        assert_approx(&buf, &expected, 1e-12, "softmax_rows_in_place");
+
+        let log_expected: Vec<f64> = expected.iter().map(|x| x.ln()).collect();
+        log_softmax_rows(&mut out, &input, 2, 3).unwrap();
+        assert_approx(&out, &log_expected, 1e-12, "log_softmax_rows");
+        let mut buf = input;
+        log_softmax_rows_in_place(&mut buf, 2, 3).unwrap();
+        assert_approx(&buf, &log_expected, 1e-12, "log_softmax_rows_in_place");
+
+        let mut lse = [0.0f64; 2];
+        logsumexp_rows(&mut lse, &input, 2, 3).unwrap();
+        assert_approx(&lse, &[logsumexp(&input[..3]), logsumexp(&input[3..])], 1e-12, "logsumexp_rows");
+
+        let result = softmax_rows(&mut out, &input, 3, 3);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+
+        // dimensions whose product overflows, which would otherwise wrap to zero here
+        let result = logsumexp_rows(&mut lse, &input, usize::MAX, 2);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { expected: usize::MAX, .. })));
+        let result = log_softmax_rows_in_place(&mut [0.0f64; 0], 1 << (usize::BITS - 1), 2);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { argument: "input", .. })));
+    }
+
+    // ── Classification and comparison masks ──
+
+    const SPECIAL: [f64; 8] = [1.0, -0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 5e-324, -2.5, 0.0];
+
+    #[test]
+    fn test_classification_masks() {
+        let mut out = [false; 8];
+        is_nan_array(&mut out, &SPECIAL).unwrap();
+        assert_eq!(out, [false, false, true, false, false, false, false, false]);
+        is_finite_array(&mut out, &SPECIAL).unwrap();
+        assert_eq!(out, [true, true, false, false, false, true, true, true]);
+        is_infinite_array(&mut out, &SPECIAL).unwrap();
+        assert_eq!(out, [false, false, false, true, true, false, false, false]);
+        is_subnormal_array(&mut out, &SPECIAL).unwrap();
+        assert_eq!(out, [false, false, false, false, false, true, false, false]);
+        signbit_array(&mut out, &SPECIAL).unwrap();
+        assert_eq!(out, [false, true, false, false, true, false, true, false]);
+
+        // subnormality is judged in the input's own precision
+        let mut out = [false; 2];
+        is_subnormal_array(&mut out, &[1e-40f32, 1e-30]).unwrap();
+        assert_eq!(out, [true, false]);
+    }
+
+    #[test]
+    fn test_mask_formats() {
+        let mut floats = [0.5f32; 8];
+        is_finite_array(&mut floats, &SPECIAL).unwrap();
+        assert_eq!(floats, [1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+
+        let mut bits = [u64::MAX; 1];
+        is_finite_array(&mut bits, &SPECIAL).unwrap();
+        assert_eq!(bits, [0b1110_0011]);
+
+        // packed masks span several words, with the unused high bits cleared
+        let input: Vec<f64> = (0..130).map(|i| i as f64).collect();
+        let mut bits = [u64::MAX; 3];
+        ge_scalar_array(&mut bits, &input, 63.0).unwrap();
+        assert_eq!(bits, [1 << 63, u64::MAX, 0b11]);
+
+        let mut too_short = [0u64; 2];
+        let result = ge_scalar_array(&mut too_short, &input, 63.0);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_comparison_masks() {
+        let a = [1.0, 2.0, f64::NAN, -0.0];
+        let b = [2.0, 2.0, 1.0, 0.0];
+        let mut out = [false; 4];
+        lt_array(&mut out, &a, &b).unwrap();
+        assert_eq!(out, [true, false, false, false]);
+        le_array(&mut out, &a, &b).unwrap();
+        assert_eq!(out, [true, true, false, true]);
+        eq_array(&mut out, &a, &b).unwrap();
+        assert_eq!(out, [false, true, false, true]);
+        ne_array(&mut out, &a, &b).unwrap();
+        assert_eq!(out, [true, false, true, false]);
+        gt_array(&mut out, &a, &b).unwrap();
+        assert_eq!(out, [false, false, false, false]);
+        ge_array(&mut out, &a, &b).unwrap();
+        assert_eq!(out, [false, true, false, true]);
+
+        lt_scalar_array(&mut out, &a, 2.0).unwrap();
+        assert_eq!(out, [true, false, false, true]);
+        le_scalar_array(&mut out, &a, 2.0).unwrap();
+        assert_eq!(out, [true, true, false, true]);
+        eq_scalar_array(&mut out, &a, 0.0).unwrap();
+        assert_eq!(out, [false, false, false, true]);
+        ne_scalar_array(&mut out, &a, 0.0).unwrap();
+        assert_eq!(out, [true, true, true, false]);
+        gt_scalar_array(&mut out, &a, 1.0).unwrap();
+        assert_eq!(out, [false, true, false, false]);
+        ge_scalar_array(&mut out, &a, 1.0).unwrap();
+        assert_eq!(out, [true, true, false, false]);
+
+        let result = lt_array(&mut out, &a, &b[..3]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_select_array() {
+        let a = [1.0, 2.0, 3.0, 4.0];
+        let b = [-1.0, -2.0, -3.0, -4.0];
+        let mut out = [0.0f64; 4];
+        select_array(&mut out, &[true, false, false, true], &a, &b).unwrap();
+        assert_eq!(out, [1.0, -2.0, -3.0, 4.0]);
+        select_array(&mut out, &[0b0110u64], &a, &b).unwrap();
+        assert_eq!(out, [-1.0, 2.0, 3.0, -4.0]);
+        let mut buf = a;
+        select_array_in_place(&mut buf, &[0.0f64, 1.0, 0.0, 1.0], &b).unwrap();
+        assert_eq!(buf, [-1.0, 2.0, -3.0, 4.0]);
+
+        let result = select_array(&mut out, &[true; 3], &a, &b);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_masked_unary() {
+        // a piecewise function, using log1p for small inputs and log elsewhere
+        let x = [1e-10, 5.0, -1e-12, 100.0];
+        let mut small = [false; 4];
+        let mut large = [false; 4];
+        lt_scalar_array(&mut small, &x, 1e-3).unwrap();
+        ge_scalar_array(&mut large, &x, 1e-3).unwrap();
+        let mut out = [f64::NAN; 4];
+        log1p_array_masked(&mut out, &x, &small).unwrap();
+        assert!(out[1].is_nan() && out[3].is_nan());
+        log_array_masked(&mut out, &x, &large).unwrap();
+        assert_approx(&out, &[1e-10f64.ln_1p(), 5f64.ln(), (-1e-12f64).ln_1p(), 100f64.ln()], 1e-12, "masked piecewise");
+
+        // elementwise functions, and masks spanning several tiles
+        let input: Vec<f64> = (0..600).map(|i| i as f64).collect();
+        let mask: Vec<u64> = vec![0xaaaa_aaaa_aaaa_aaaa; 10];
+        let mut out = vec![-1.0f64; 600];
+        sqr_array_masked(&mut out, &input, &mask).unwrap();
+        let mut sin_out = vec![-1.0f64; 600];
+        sin_array_masked(&mut sin_out, &input, &mask).unwrap();
+        for i in 0..600 {
+            let expected = if i % 2 == 1 { (i * i) as f64 } else { -1.0 };
+            assert_eq!(out[i], expected);
+            let expected = if i % 2 == 1 { (i as f64).sin() } else { -1.0 };
+            assert!((sin_out[i] - expected).abs() < 1e-10);
+        }
+
+        let result = sin_array_masked(&mut out, &input, &mask[..9]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    // ── domain checking ──
+
+    #[test]
+    fn test_domain_contains() {
+        assert!(Domain::NonNegative.contains(0.0f64) && !Domain::NonNegative.contains(-1e-300f64));
+        assert!(Domain::AtLeastMinusOne.contains(-1.0f64) && !Domain::AtLeastMinusOne.contains(-1.5f64));
+        assert!(Domain::UnitInterval.contains(-1.0f32) && !Domain::UnitInterval.contains(f32::INFINITY));
+        assert!(Domain::AtLeastOne.contains(f64::INFINITY) && !Domain::AtLeastOne.contains(0.5f64));
+        assert!(Domain::UnitInterval.contains(f64::NAN));
+
+        let input = [0.5, -2.0, 1.0, 3.0, f64::NAN];
+        let violations: Vec<usize> = Domain::UnitInterval.violations(&input).collect();
+        assert_eq!(violations, vec![1, 3]);
+        let mut mask = [0u64; 1];
+        Domain::UnitInterval.violations_mask(&mut mask, &input).unwrap();
+        assert_eq!(mask, [0b01010]);
+    }
+
+    #[test]
+    fn test_checked_arrays() {
+        let mut out = [0.0f64; 4];
+        acos_array_checked(&mut out, &[0.1, -0.3, 0.9, 1.0]).unwrap();
+        assert_approx(&out, &[0.1f64.acos(), (-0.3f64).acos(), 0.9f64.acos(), 0.0], 1e-12, "acos_array_checked");
+
+        let mut out = [7.0f64; 4];
+        let result = log_array_checked(&mut out, &[1.0, 2.0, -0.5, -3.0]);
+        assert!(matches!(result, Err(AccelerateError::DomainError { index: 2, value }) if value == -0.5));
+        assert_eq!(out, [7.0; 4]);
+
+        let mut buf = [2.0f32, 0.5];
+        let result = acosh_array_in_place_checked(&mut buf);
+        assert!(matches!(result, Err(AccelerateError::DomainError { index: 1, .. })));
+        assert_eq!(buf, [2.0, 0.5]);
+
+        let mut buf = [0.0f64, -1.0, f64::NAN];
+        log1p_array_in_place_checked(&mut buf).unwrap();
+        assert_eq!(&buf[..2], &[0.0, f64::NEG_INFINITY]);
+
+        let result = sqrt_array_checked(&mut out, &[1.0; 3]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_binary_domains() {
+        let bases = [2.0f64, -8.0, -8.0, f64::NEG_INFINITY, -1.0];
+        let exponents = [0.5, 3.0, 1.0 / 3.0, 0.5, f64::INFINITY];
+        let violations: Vec<usize> = BinaryDomain::RealPower.violations(&bases, &exponents).collect();
+        assert_eq!(violations, vec![2]);
+        assert!(BinaryDomain::NonZeroDivisor.contains(1.0f32, -0.5) && !BinaryDomain::NonZeroDivisor.contains(1.0f32, 0.0));
+        assert!(!BinaryDomain::NonZeroDivisor.contains(f64::INFINITY, 2.0));
+        assert!(!BinaryDomain::NotBothZero.contains(-0.0f64, 0.0) && BinaryDomain::NotBothZero.contains(0.0f64, f64::NAN));
+        let mut mask = [0u64; 1];
+        BinaryDomain::RealPower.violations_mask(&mut mask, &bases, &exponents).unwrap();
+        assert_eq!(mask, [0b100]);
+
+        let mut out = [7.0f64; 5];
+        let result = pow_array_checked(&mut out, &bases, &exponents);
+        assert!(matches!(result, Err(AccelerateError::DomainError { index: 2, value }) if value == -8.0));
+        assert_eq!(out, [7.0; 5]);
+        pow_array_checked(&mut out[..2], &bases[..2], &exponents[..2]).unwrap();
+        assert_eq!(&out[..2], &[2.0f64.sqrt(), -512.0]);
+
+        let mut denominators = [3.0f64, 0.0];
+        let result = fmod_array_in_place_rhs_checked(&[5.0, 5.0], &mut denominators);
+        assert!(matches!(result, Err(AccelerateError::DomainError { index: 1, value }) if value == 0.0));
+        assert_eq!(denominators, [3.0, 0.0]);
+        let mut numerators = [5.0f64, -7.5];
+        remainder_array_in_place_checked(&mut numerators, &[3.0, 2.0]).unwrap();
+        assert_eq!(numerators, [-1.0, 0.5]);
+
+        let mut y = [1.0f32, 0.0];
+        assert!(atan2_array_in_place_checked(&mut y, &[1.0, 0.0]).is_err());
+
+        // length mismatches name the function's own arguments
+        let result = pow_array_in_place_checked(&mut [1.0f64, 2.0], &[1.0]);
+        assert_eq!(result, Err(AccelerateError::LengthMismatch { argument: "exponents", reference: "bases", expected: 2, got: 1 }));
+        let result = fmod_array_in_place_rhs_checked(&[1.0f64], &mut [1.0, 2.0]);
+        assert_eq!(result, Err(AccelerateError::LengthMismatch { argument: "denominator", reference: "numerator", expected: 1, got: 2 }));
+        let result = atan2_array_in_place_rhs_checked(&[1.0f32, 2.0], &mut [1.0]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { argument: "x", reference: "y", .. })));
+        assert!(matches!(atan2_array_checked(&mut out, &bases, &exponents[..4]), Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    // ── output summaries ──
+
+    #[test]
+    fn test_output_summary() {
+        let values = [1.0, f64::NAN, 0.0, f64::INFINITY, 1e-310, -0.0, f64::NEG_INFINITY];
+        let summary = OutputSummary::of(&values);
+        assert_eq!(summary.nan, ClassSummary { count: 1, first: Some(1) });
+        assert_eq!(summary.infinite, ClassSummary { count: 2, first: Some(3) });
+        assert_eq!(summary.subnormal, ClassSummary { count: 1, first: Some(4) });
+        assert_eq!(summary.zero, ClassSummary { count: 2, first: Some(2) });
+        assert_eq!(summary.first_non_finite(), Some(1));
+        assert!(OutputSummary::of(&[1.0f32, 0.0]).is_finite());
+
+        assert_eq!(check_finite_array(&values), Err(AccelerateError::NonFinite { index: 1, value: f64::NAN }));
+        assert_eq!(check_finite_array(&[1.0f32, -0.0]), Ok(()));
+    }
+
+    #[test]
+    fn test_summarize() {
+        // results spanning several tiles, with the first overflow in the second
+        let input: Vec<f64> = (0..600).map(|i| if i % 300 == 299 { 1000.0 } else { 0.0 }).collect();
+        let mut out = vec![0.0f64; 600];
+        let summary = summarize_unary(exp_array, &mut out, &input).unwrap();
+        assert_eq!(summary.infinite, ClassSummary { count: 2, first: Some(299) });
+        assert!(out[..299].iter().all(|&x| x == 1.0));
+
+        let mut buf = input.clone();
+        assert_eq!(summarize_unary_in_place(exp_array_in_place, &mut buf), summary);
+        assert_eq!(buf, out);
+
+        let bases = [2.0, 0.0, -1.0, 10.0];
+        let exponents = [0.5, -1.0, 0.5, -400.0];
+        let mut out = [0.0f64; 4];
+        let summary = summarize_binary(pow_array, &mut out, &bases, &exponents).unwrap();
+        assert_eq!((summary.infinite.first, summary.nan.first, summary.zero.first), (Some(1), Some(2), Some(3)));
+        let mut a = bases;
+        assert_eq!(summarize_binary_in_place(pow_array_in_place, &mut a, &exponents).unwrap(), summary);
+
+        let result = summarize_unary(exp_array, &mut out, &[1.0; 3]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    // ── fixed-size arrays ──
+
+    #[test]
+    fn test_fixed_unary_and_binary() {
+        let mut out = [0.0f64; 4];
+        sin_fixed(&mut out, &INPUTS);
+        let expected: Vec<f64> = INPUTS.iter().map(|x| x.sin()).collect();
+        assert_approx(&out, &expected, 1e-12, "sin_fixed");
+        assert_eq!(sin_fixed_value(&INPUTS), out);
+
+        let bases = [1.0f64, 6.0, 2.5];
+        let exponents = [2.0, 4.0, 1.3];
+        assert_eq!(pow_fixed_value(&bases, &exponents), [1.0, 1296.0, 2.5f64.powf(1.3)]);
+        assert_eq!(add_scalar_fixed_value(&bases, 1.0), [2.0, 7.0, 3.5]);
+        assert_eq!(clamp_scalar_fixed_value(&bases, 2.0, 3.0), [2.0, 3.0, 2.5]);
+        assert_eq!(powi_fixed_value(&[2.0f32, -3.0], 3), [8.0, -27.0]);
+        assert_eq!(sqr_fixed_value::<f64, 0>(&[]), [0.0f64; 0]);
+    }
+
+    #[test]
+    fn test_fixed_multiple_outputs() {
+        let (sin, cos) = sincos_fixed_value(&INPUTS);
+        let mut sin_out = [0.0f64; 4];
+        let mut cos_out = [0.0f64; 4];
+        sincos_fixed(&mut sin_out, &mut cos_out, &INPUTS);
+        assert_eq!((sin, cos), (sin_out, cos_out));
+
+        let (mantissa, exponent) = frexp_fixed_value(&[8.0f64, 0.75]);
+        assert_eq!((mantissa, exponent), ([0.5, 0.75], [4, 0]));
+        assert_eq!(ldexp_fixed_value(&mantissa, &exponent), [8.0, 0.75]);
+        assert_eq!(modf_fixed_value(&[2.5f64, -1.25]), ([2.0, -1.0], [0.5, -0.25]));
+        assert_eq!(cosisin_fixed_value(&[0.0f64])[0].unpack(), (1.0, 0.0));
+    }
+
+    // ── raw pointers ──
+
+    #[test]
+    fn test_raw_unary() {
+        let mut out = [0.0f64; 4];
+        unsafe { raw::sin(out.as_mut_ptr(), INPUTS.as_ptr(), 4).unwrap() };
+        let expected: Vec<f64> = INPUTS.iter().map(|x| x.sin()).collect();
+        assert_approx(&out, &expected, 1e-12, "raw::sin");
+
+        let mut buf = INPUTS;
+        let ptr = buf.as_mut_ptr();
+        unsafe { raw::sin(ptr, ptr, 4).unwrap() };
+        assert_eq!(buf, out);
+
+        let result = unsafe { raw::sin(ptr.add(1), ptr, 3) };
+        assert_eq!(result, Err(AccelerateError::Overlap { first: "out", second: "input" }));
+        assert_eq!(buf, out);
+    }
+
+    #[test]
+    fn test_raw_binary_and_multiple_outputs() {
+        let mut a = [1.0f64, 6.0, 2.5];
+        let b = [2.0, 4.0, 1.3];
+        let pa = a.as_mut_ptr();
+        unsafe { raw::pow(pa, pa, b.as_ptr(), 3).unwrap() };
+        assert_eq!(a, [1.0, 1296.0, 2.5f64.powf(1.3)]);
+        let mut e = [2.0f64, 0.5];
+        let pe = e.as_mut_ptr();
+        unsafe { raw::pow(pe, [3.0, 16.0].as_ptr(), pe, 2).unwrap() };
+        assert_eq!(e, [9.0, 4.0]);
+        let result = unsafe { raw::pow(pa, b.as_ptr(), pa.add(1), 2) };
+        assert_eq!(result, Err(AccelerateError::Overlap { first: "out", second: "exponents" }));
+
+        // the output may equal several identical inputs, across more than one tile
+        let mut p: Vec<f64> = (0..600).map(|i| 1.0 + i as f64 / 600.0).collect();
+        let expected: Vec<f64> = p.iter().map(|x| x.powf(*x)).collect();
+        let pp = p.as_mut_ptr();
+        unsafe { raw::pow(pp, pp, pp, 600).unwrap() };
+        assert_approx(&p, &expected, 1e-12, "raw::pow aliased");
+        let mut f = [1.0f64, 2.0, 3.0];
+        let pf = f.as_mut_ptr();
+        unsafe { raw::fma(pf, pf, pf, [1.0, 1.0, 1.0].as_ptr(), 3).unwrap() };
+        assert_eq!(f, [2.0, 5.0, 10.0]);
+        let result = unsafe { raw::fma(pf, pf, pf.add(1), pf, 2) };
+        assert_eq!(result, Err(AccelerateError::Overlap { first: "out", second: "b" }));
+        let result = unsafe { raw::sin(pf, pf.cast_const().wrapping_add(usize::MAX / 16), usize::MAX / 8) };
+        assert_eq!(result, Err(AccelerateError::Overlap { first: "out", second: "input" }));
+
+        let mut buf = [0.0f64, 0.5, 1.0];
+        let mut cos = [0.0f64; 3];
+        let ptr = buf.as_mut_ptr();
+        unsafe { raw::sincos(ptr, cos.as_mut_ptr(), ptr, 3).unwrap() };
+        assert_approx(&buf, &[0.0, 0.5f64.sin(), 1.0f64.sin()], 1e-12, "raw::sincos sin");
+        assert_approx(&cos, &[1.0, 0.5f64.cos(), 1.0f64.cos()], 1e-12, "raw::sincos cos");
+        let result = unsafe { raw::sincos(ptr, ptr, cos.as_ptr(), 3) };
+        assert_eq!(result, Err(AccelerateError::Overlap { first: "sin_out", second: "cos_out" }));
+
+        let mut exponents = [0i32; 2];
+        let mut values = [8.0f64, 0.75];
+        let pv = values.as_mut_ptr();
+        unsafe { raw::frexp(pv, exponents.as_mut_ptr(), pv, 2).unwrap() };
+        assert_eq!((values, exponents), ([0.5, 0.75], [4, 0]));
+    }
+
+    // ── in-place on the second operand ──
+
+    #[test]
+    fn test_in_place_rhs() {
+        let mut denominators = [2.0f64, 4.0, 0.5];
+        div_array_in_place_rhs(&[1.0, 2.0, 3.0], &mut denominators).unwrap();
+        assert_eq!(denominators, [0.5, 0.5, 6.0]);
+
+        let mut exponents = [2.0f64, 0.5];
+        pow_array_in_place_rhs(&[3.0, 16.0], &mut exponents).unwrap();
+        assert_eq!(exponents, [9.0, 4.0]);
+
+        let mut x = [1.0f64, -1.0];
+        atan2_array_in_place_rhs(&[1.0, 1.0], &mut x).unwrap();
+        assert_approx(&x, &[core::f64::consts::FRAC_PI_4, 3.0 * core::f64::consts::FRAC_PI_4], 1e-12, "atan2_array_in_place_rhs");
+
+        let mut sign = [-1.0f32, 1.0];
+        copysign_array_in_place_rhs(&[2.0, -3.0], &mut sign).unwrap();
+        assert_eq!(sign, [-2.0, 3.0]);
+
+        let result = fmod_array_in_place_rhs(&[1.0, 2.0], &mut [1.0f64; 3]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    // ── runtime dispatch ──
+
+    #[test]
+    fn test_op_names() {
+        for &op in Op::ALL {
+            assert_eq!(alloc::format!("{}", op).parse::<Op>(), Ok(op));
+        }
+        assert_eq!("log1p".parse::<Op>(), Ok(Op::Log1p));
+        assert_eq!("abs_diff".parse::<Op>(), Ok(Op::AbsDiff));
+        assert_eq!("log1p_array".parse::<Op>(), Err(ParseOpError));
+        assert_eq!((Op::Sin.arity(), Op::Atan2.arity()), (1, 2));
+        assert_eq!(Op::Acosh.domain(), Some(Domain::AtLeastOne));
+        assert_eq!(Op::Exp.domain(), None);
+        assert_eq!(Op::Pow.binary_domain(), Some(BinaryDomain::RealPower));
+        assert_eq!(Op::Atan2d.binary_domain(), Some(BinaryDomain::NotBothZero));
+        assert_eq!(Op::Add.binary_domain(), None);
+        assert_eq!(Op::Log.binary_domain(), None);
+    }
+
+    #[test]
+    fn test_op_apply() {
+        let mut out = [0.0f64; 4];
+        Op::Log1p.apply(&mut out, &[&POSITIVE]).unwrap();
+        let mut expected = [0.0f64; 4];
+        log1p_array(&mut expected, &POSITIVE).unwrap();
+        assert_eq!(out, expected);
+
+        Op::Pow.apply(&mut out, &[&POSITIVE, &[2.0; 4]]).unwrap();
+        assert_eq!(out, [0.0625, 0.25, 1.0, 16.0]);
+
+        let result = Op::Pow.apply(&mut out, &[&POSITIVE]);
+        assert_eq!(result, Err(AccelerateError::ArityMismatch { expected: 2, got: 1 }));
+        let result = Op::Sin.apply(&mut out, &[&POSITIVE[..3]]);
+        assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
+    }
+
+    #[cfg(feature = "npy")]
+    #[test]
+    fn test_npy_roundtrip() {
+        use super::npy::*;
+        let array = NpyArray::new(vec![0.5f64, 1.0, 2.0, 3.5, 4.0, 5.0], vec![2, 3], true).unwrap();
+        let bytes = array.to_bytes();
+        assert_eq!(bytes.len() % 64, 48);
+        let read = NpyArray::<f64>::from_bytes(&bytes).unwrap();
+        assert_eq!(read.shape(), &[2, 3]);
+        assert!(read.fortran_order());
+        assert_eq!(read.data(), array.data());
+
+        let exp = read.map_array(exp_array).unwrap();
+        let expected: Vec<f64> = array.data().iter().map(|x| x.exp()).collect();
+        assert_approx(exp.data(), &expected, 1e-14, "npy exp_array");
+
+        // a big-endian float32 array, as written by numpy with dtype '>f4'
+        let mut bytes = std::vec::Vec::from(*b"\x93NUMPY\x01\x00\x76\x00");
+        bytes.extend_from_slice(b"{'descr': '>f4', 'fortran_order': False, 'shape': (2,), }");
+        bytes.resize(128 - 1, b' ');
+        bytes.push(b'\n');
+        bytes.extend_from_slice(&1.5f32.to_be_bytes());
+        bytes.extend_from_slice(&(-2.0f32).to_be_bytes());
+        let read = NpyArray::<f32>::from_bytes(&bytes).unwrap();
+        assert_eq!(read.data(), &[1.5, -2.0]);
+        let result = NpyArray::<f64>::from_bytes(&bytes);
+        assert!(matches!(result, Err(NpyError::Dtype { expected: "f8", .. })));
+    }
+
+    #[cfg(feature = "npy")]
+    #[test]
+    fn test_npy_malformed() {
+        use super::npy::*;
+        let npy = |header: &str| {
+            let mut bytes = std::vec::Vec::from(*b"\x93NUMPY\x01\x00\x76\x00");
+            bytes.extend_from_slice(header.as_bytes());
+            bytes.resize(128 - 1, b' ');
+            bytes.push(b'\n');
+            bytes.extend_from_slice(&[0; 16]);
+            NpyArray::<f64>::from_bytes(&bytes)
+        };
+        assert!(npy("{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }").is_ok());
+        assert!(matches!(npy("{'descr': '', 'fortran_order': False, 'shape': (2,), }"), Err(NpyError::Format(_))));
+        assert!(matches!(npy("{'descr': '\u{e9}f8', 'fortran_order': False, 'shape': (2,), }"), Err(NpyError::Format(_))));
+        let overflow = "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296, 2), }";
+        assert!(matches!(npy(overflow), Err(NpyError::Format(_))));
+    }
+
+    #[cfg(feature = "npy")]
+    #[test]
+    fn test_npz_complex_roundtrip() {
+        use super::npy::*;
+        let angles = NpyArray::from_vec(vec![0.0f32, 0.5, 1.0]);
+        let unit = angles.map_array(cosisin_array).unwrap();
+
+        let mut writer = NpzWriter::new(std::io::Cursor::new(Vec::new()));
+        writer.add("angles", &angles).unwrap();
+        writer.add("unit", &unit).unwrap();
+        let archive = writer.finish().unwrap().into_inner();
+
+        let mut reader = NpzReader::new(std::io::Cursor::new(archive)).unwrap();
+        assert_eq!(reader.names().collect::<Vec<_>>(), ["angles", "unit"]);
+        assert_eq!(reader.array::<f32>("angles").unwrap().data(), angles.data());
+        let read = reader.array::<AccelerateComplex<f32>>("unit").unwrap();
+        for (x, (read, written)) in angles.data().iter().zip(read.data().iter().zip(unit.data())) {
+            assert_eq!(read.unpack(), written.unpack());
+            assert!((read.unpack().1 - x.sin()).abs() < 1e-6);
+        }
+        assert!(matches!(reader.array::<f32>("missing"), Err(NpyError::MissingArray(_))));
+        assert!(matches!(reader.array::<f64>("angles"), Err(NpyError::Dtype { .. })));
+    }
+
+    #[cfg(feature = "python")]
+    #[test]
+    fn test_python_module() {
+        use pyo3::prelude::*;
+        Python::initialize();
+        Python::attach(|py| {
+            let module = pyo3::wrap_pymodule!(super::python::vforce)(py);
+            let module = module.bind(py);
+            for op in Op::ALL {
+                let ufunc = module.getattr(op.name()).unwrap();
+                assert_eq!(ufunc.getattr("nin").unwrap().extract::<usize>().unwrap(), op.arity());
+            }
+            let sin = module.getattr("sin").unwrap();
+            assert_eq!(sin.repr().unwrap().to_str().unwrap(), "<vforce.ufunc 'sin'>");
+            assert!(sin.call0().unwrap_err().is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        });
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_ffi() {
+        use super::ffi;
+        let mut out = [0.0f64; 4];
+        let status = unsafe { ffi::f64::sin(INPUTS.as_ptr(), out.as_mut_ptr(), 4) };
+        assert_eq!(status, ffi::VFORCE_OK);
+        let expected: Vec<f64> = INPUTS.iter().map(|x| x.sin()).collect();
+        assert_approx(&out, &expected, 1e-14, "ffi sin");
+
+        let mut buf = POSITIVE;
+        let ptr = buf.as_mut_ptr();
+        assert_eq!(unsafe { ffi::f64::exp(ptr, ptr.wrapping_add(1), 3) }, ffi::VFORCE_ERROR_OVERLAP);
+        assert_eq!(unsafe { ffi::f64::exp(core::ptr::null(), ptr, 4) }, ffi::VFORCE_ERROR_NULL_POINTER);
+        assert_eq!(unsafe { ffi::f64::exp(core::ptr::null(), core::ptr::null_mut(), 0) }, ffi::VFORCE_OK);
+        assert_eq!(
+            ffi::status(Err(AccelerateError::DomainError { index: 0, value: -1.0 })),
+            ffi::VFORCE_ERROR_DOMAIN
+        );
+    }
+
+    #[test]
+    fn test_stream_tiles() {
+        let input: Vec<f64> = (0..10).map(|i| i as f64 * 0.3).collect();
+        let mut expected = vec![0.0f64; 10];
+        exp_array(&mut expected, &input).unwrap();
+
+        let tiled: Vec<f64> = input.iter().copied().vf_map_tiled::<3>(Op::Exp).collect();
+        assert_eq!(tiled, expected);
+        let streamed = super::stream::exp(input.clone());
+        assert_eq!(streamed.len(), 10);
+        assert_eq!(streamed.collect::<Vec<_>>(), expected);
+        assert_eq!(core::iter::empty::<f32>().vf_map(Op::Sin).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_stream_rejects_softmax() {
+        let _ = INPUTS.iter().copied().vf_map(Op::Softmax);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_transform() {
+        use super::io::*;
+        use core::num::NonZeroUsize;
+        let input: Vec<f32> = (0..3 * BLOCK_LEN + 5).map(|i| (i % 1000) as f32 * 0.01).collect();
+        let bytes: Vec<u8> = input.iter().flat_map(|x| x.to_be_bytes()).collect();
+        let mut expected = vec![0.0f32; input.len()];
+        log1p_array(&mut expected, &input).unwrap();
+
+        let mut serial = Vec::new();
+        let count = transform(&bytes[..], &mut serial, Op::Log1p, Endianness::Big, Precision::F32).unwrap();
+        assert_eq!(count, input.len() as u64);
+        let decoded: Vec<f32> = serial.chunks_exact(4).map(|b| f32::from_be_bytes(b.try_into().unwrap())).collect();
+        assert_eq!(decoded, expected);
+
+        let mut parallel = Vec::new();
+        let threads = NonZeroUsize::new(2).unwrap();
+        transform_parallel(&bytes[..], &mut parallel, Op::Log1p, Endianness::Big, Precision::F32, threads).unwrap();
+        assert_eq!(parallel, serial);
+
+        let mut out = Vec::new();
+        let result = transform(&bytes[..6], &mut out, Op::Exp, Endianness::Big, Precision::F32);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(out.len(), 4);
+        let result = transform(&bytes[..], &mut out, Op::Softmax, Endianness::Big, Precision::F32);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_polyval_horner_and_estrin() {
+        let coeffs = [0.5, -1.0, 2.0, 0.25, -0.125, 3.0, 1.5];
+        let x: Vec<f64> = (0..600).map(|i| i as f64 * 0.005 - 1.5).collect();
+        let expected: Vec<f64> = x.iter()
+            .map(|&x| coeffs.iter().enumerate().map(|(i, c)| c * x.powi(i as i32)).sum())
+            .collect();
+
+        let mut out = vec![0.0f64; x.len()];
+        polyval_array(&mut out, &x, &coeffs).unwrap();
+        assert_approx(&out, &expected, 1e-12, "polyval_array");
+        polyval_estrin_array(&mut out, &x, &coeffs).unwrap();
+        assert_approx(&out, &expected, 1e-12, "polyval_estrin_array");
+
+        let mut in_place = x.clone();
+        polyval_estrin_array_in_place(&mut in_place, &coeffs);
+        assert_eq!(in_place, out);
+        polyval_array_in_place(&mut in_place, &[]);
+        assert!(in_place.iter().all(|&y| y == 0.0));
+        assert!(polyval_array(&mut out[..3], &x, &coeffs).is_err());
+    }
+
+    #[test]
+    fn test_chebyshev_and_rational_eval() {
+        let x = [-1.0, -0.3, 0.0, 0.6, 1.0];
+        let coeffs = [1.0, 0.5, -2.0, 0.25];
+        let mut out = [0.0f64; 5];
+        chebyshev_eval_array(&mut out, &x, &coeffs).unwrap();
+        let expected: Vec<f64> = x.iter()
+            .map(|&x| 1.0 + 0.5 * x - 2.0 * (2.0 * x * x - 1.0) + 0.25 * (4.0 * x * x * x - 3.0 * x))
+            .collect();
+        assert_approx(&out, &expected, 1e-14, "chebyshev_eval_array");
+
+        let mut in_place = x;
+        rational_eval_array_in_place(&mut in_place, &[1.0, 1.0], &[1.0, 0.0, 1.0]);
+        let expected: Vec<f64> = x.iter().map(|&x| (1.0 + x) / (1.0 + x * x)).collect();
+        assert_approx(&in_place, &expected, 1e-15, "rational_eval_array_in_place");
+    }
+
+    #[test]
+    fn test_space_fill() {
+        let mut grid = [0.0f64; 7];
+        linspace_fill(&mut grid, -1.0, 0.2);
+        assert_eq!(grid[0], -1.0);
+        assert_eq!(grid[6], 0.2);
+        assert_approx(&grid, &[-1.0, -0.8, -0.6, -0.4, -0.2, 0.0, 0.2], 1e-15, "linspace_fill");
+
+        arange_fill(&mut grid, 1.0, 0.1);
+        assert_eq!(grid[6], 6.0f64.mul_add(0.1, 1.0));
+        logspace_fill(&mut grid[..3], 0.0, 2.0, 10.0);
+        assert_approx(&grid[..3], &[1.0, 10.0, 100.0], 1e-12, "logspace_fill");
+        geomspace_fill(&mut grid[..4], -2.0, -16.0).unwrap();
+        assert_approx(&grid[..4], &[-2.0, -4.0, -8.0, -16.0], 1e-14, "geomspace_fill");
+        assert!(matches!(geomspace_fill(&mut grid, 1.0, -1.0), Err(AccelerateError::DomainError { index: 6, .. })));
+    }
+
+    #[test]
+    fn test_fused_linspace() {
+        let mut grid = vec![0.0f64; 3 * TILE + 7];
+        linspace_fill(&mut grid, 0.0, 10.0);
+        let mut expected = vec![0.0f64; grid.len()];
+        sin_array(&mut expected, &grid).unwrap();
+        let mut out = vec![0.0f64; grid.len()];
+        sin_linspace(&mut out, 0.0, 10.0);
+        assert_eq!(out, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_space_vec() {
+        assert_eq!(arange(0.0f64, 1.0, 0.25), vec![0.0, 0.25, 0.5, 0.75]);
+        assert!(arange(0.0f64, 1.0, -0.25).is_empty());
+        assert_eq!(linspace(2.0f32, 3.0, 3), vec![2.0, 2.5, 3.0]);
+        assert_eq!(geomspace(1.0f64, 8.0, 4).unwrap()[3], 8.0);
+        assert_eq!(logspace(0.0f64, 3.0, 4, 2.0).len(), 4);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_arange_degenerate_steps() {
+        assert!(arange(0.0f64, 1.0, 0.0).is_empty());
+        assert!(arange(1.0f64, 1.0, 0.0).is_empty());
+        assert!(arange(0.0f64, 1.0, f64::NAN).is_empty());
+        assert!(arange(0.0f32, 1.0, -0.5).is_empty());
+        assert!(arange(0.0f64, f64::INFINITY, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_cosine_windows() {
+        use core::f64::consts::PI;
+        let mut w = [0.0f64; 9];
+        hann(&mut w, Symmetry::Symmetric);
+        let expected: Vec<f64> = (0..9).map(|n| 0.5 - 0.5 * (2.0 * PI * n as f64 / 8.0).cos()).collect();
+        assert_approx(&w, &expected, 1e-15, "hann symmetric");
+        let mut periodic = [0.0f64; 8];
+        hann(&mut periodic, Symmetry::Periodic);
+        assert_approx(&periodic, &expected[..8], 1e-15, "hann periodic");
+
+        blackman(&mut w, Symmetry::Symmetric);
+        assert!(w[0].abs() < 1e-15 && (w[4] - 1.0).abs() < 1e-15);
+        flat_top(&mut w, Symmetry::Symmetric);
+        assert!((w[4] - 1.0).abs() < 1e-6);
+        let mut single = [0.0f32; 1];
+        hamming(&mut single, Symmetry::Symmetric);
+        assert_eq!(single, [1.0]);
+    }
+
+    #[test]
+    fn test_parameterized_windows() {
+        let mut w = [0.0f64; 9];
+        let mut reference = [0.0f64; 9];
+        tukey(&mut w, 1.0, Symmetry::Symmetric);
+        hann(&mut reference, Symmetry::Symmetric);
+        assert_approx(&w, &reference, 1e-15, "tukey alpha 1");
+        tukey(&mut w, 0.5, Symmetry::Symmetric);
+        assert_eq!(&w[2..7], &[1.0; 5]);
+        assert!(w[0].abs() < 1e-15);
+
+        kaiser(&mut w, 0.0, Symmetry::Symmetric);
+        assert_eq!(w, [1.0; 9]);
+        kaiser(&mut w, 8.6, Symmetry::Symmetric);
+        assert_eq!(w[4], 1.0);
+        assert_approx(&w[..4], &[w[8], w[7], w[6], w[5]], 1e-15, "kaiser symmetry");
+        gaussian(&mut w, 2.0, Symmetry::Symmetric);
+        assert_eq!(w[4], 1.0);
+        assert_approx(&[w[2]], &[(-0.5f64).exp()], 1e-15, "gaussian");
+
+        let mut signal = [2.0f64; 9];
+        apply_window_in_place(&mut signal, &w).unwrap();
+        assert_eq!(signal[4], 2.0);
+        assert!(apply_window_in_place(&mut signal, &w[..8]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_fft_matches_dft() {
+        use super::fft::FftPlan;
+        use core::f64::consts::PI;
+        for len in [1, 2, 7, 8, 12, 16, 30, 49] {
+            let input: Vec<AccelerateComplex<f64>> =
+                (0..len).map(|n| AccelerateComplex::new((n as f64).sin(), 0.5 - (n % 3) as f64)).collect();
+            let dft: Vec<(f64, f64)> = (0..len)
+                .map(|k| {
+                    input.iter().enumerate().fold((0.0, 0.0), |(re, im), (n, z)| {
+                        let (x, y) = z.unpack();
+                        let (s, c) = (-2.0 * PI * ((k * n) % len) as f64 / len as f64).sin_cos();
+                        (re + x * c - y * s, im + x * s + y * c)
+                    })
+                })
+                .collect();
+            let plan = FftPlan::<f64>::new(len);
+            let mut buffer = input.clone();
+            plan.forward(&mut buffer).unwrap();
+            for (z, &(re, im)) in buffer.iter().zip(&dft) {
+                let (x, y) = z.unpack();
+                assert!((x - re).abs() < 1e-12 && (y - im).abs() < 1e-12, "fft of length {len}");
+            }
+            plan.inverse(&mut buffer).unwrap();
+            for (z, w) in buffer.iter().zip(&input) {
+                let ((x, y), (a, b)) = (z.unpack(), w.unpack());
+                assert!((x - a).abs() < 1e-13 && (y - b).abs() < 1e-13, "inverse fft of length {len}");
+            }
+        }
+        assert!(FftPlan::<f32>::new(4).forward(&mut [AccelerateComplex::new(0.0, 0.0); 3]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_real_fft() {
+        use super::fft::{FftPlan, RealFftPlan};
+        for len in [2, 9, 10, 24] {
+            let input: Vec<f64> = (0..len).map(|n| (n as f64 * 0.7).cos() + n as f64 / 4.0).collect();
+            let mut full: Vec<_> = input.iter().map(|&x| AccelerateComplex::new(x, 0.0)).collect();
+            FftPlan::new(len).forward(&mut full).unwrap();
+
+            let plan = RealFftPlan::<f64>::new(len);
+            let mut spectrum = vec![AccelerateComplex::new(0.0, 0.0); plan.spectrum_len()];
+            plan.forward(&mut spectrum, &input).unwrap();
+            for (z, w) in spectrum.iter().zip(&full) {
+                let ((x, y), (a, b)) = (z.unpack(), w.unpack());
+                assert!((x - a).abs() < 1e-12 && (y - b).abs() < 1e-12, "real fft of length {len}");
+            }
+            let mut out = vec![0.0f64; len];
+            plan.inverse(&mut out, &spectrum).unwrap();
+            assert_approx(&out, &input, 1e-13, "inverse real fft");
+
+            // the imaginary parts of the first element and, for even lengths, the last are ignored
+            let ends = if len % 2 == 0 { vec![0, len / 2] } else { vec![0] };
+            for k in ends {
+                spectrum[k] = AccelerateComplex::new(spectrum[k].unpack().0, 3.0);
+            }
+            plan.inverse(&mut out, &spectrum).unwrap();
+            assert_approx(&out, &input, 1e-13, "inverse real fft with imaginary ends");
+        }
+    }
+
     // ── f32 spot check ──
 
     #[test]
@@ -836,4 +2601,38 @@ mod tests {
         let result = pow_array(&mut out, &a, &b);
         assert!(matches!(result, Err(AccelerateError::LengthMismatch { .. })));
     }
+
+    #[test]
+    fn test_length_mismatch_arguments() {
+        let mut out = [0.0f64; 4];
+        let result = pow_array(&mut out, &[1.0; 4], &[2.0; 3]);
+        assert_eq!(result, Err(AccelerateError::LengthMismatch {
+            argument: "exponents", reference: "bases", expected: 4, got: 3
+        }));
+        let result = sin_array(&mut out, &[1.0; 5]);
+        assert_eq!(result, Err(AccelerateError::LengthMismatch {
+            argument: "out", reference: "input", expected: 5, got: 4
+        }));
+        let mut mask = [0u64; 2];
+        let result = is_nan_array(&mut mask, &[1.0f64; 64]);
+        assert_eq!(result, Err(AccelerateError::LengthMismatch {
+            argument: "out", reference: "input", expected: 1, got: 2
+        }));
+    }
+
+    #[test]
+    fn test_error_traits() {
+        let err = AccelerateError::LengthMismatch { argument: "out", reference: "input", expected: 4, got: 3 };
+        let boxed: alloc::boxed::Box<dyn core::error::Error> = alloc::boxed::Box::new(err);
+        assert_eq!(
+            alloc::format!("{}", boxed),
+            "AccelerateError::LengthMismatch - vforce received arrays of different lengths: `out` has 3 elements, expected 4 to match `input`"
+        );
+
+        let nan = AccelerateError::NonFinite { index: 2, value: f64::NAN };
+        assert_eq!(nan, nan);
+        assert_ne!(nan, AccelerateError::NonFinite { index: 2, value: f64::INFINITY });
+        assert_ne!(nan, AccelerateError::DomainError { index: 2, value: f64::NAN });
+        assert_ne!(err, AccelerateError::Overlap { first: "out", second: "input" });
+    }
 }
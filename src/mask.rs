@@ -0,0 +1,250 @@
+//! Groups together floating-point classification and comparison predicates, which write their
+//! results as masks
+//!
+//! Masks can be written as `bool`s, as `f32` or `f64` values of 1.0 and 0.0, or packed into
+//! `u64` words, with element i stored in bit i % 64 of word i / 64. A packed mask over n
+//! elements must be exactly n.div_ceil(64) words long, and any unused high bits of the last word
+//! are cleared.
+//!
+//! ```rust
+//! use vforce::mask::{is_finite_array, gt_scalar_array};
+//! let input: Vec<f64> = vec![1.0, f64::NAN, -2.0, f64::INFINITY];
+//!
+//! let mut finite = vec![false; 4];
+//! is_finite_array(&mut finite, &input).unwrap();
+//! assert_eq!(finite, vec![true, false, true, false]);
+//!
+//! let mut positive = vec![0u64; 1];
+//! gt_scalar_array(&mut positive, &input, 0.0).unwrap();
+//! assert_eq!(positive, vec![0b1001]);
+//! ```
+use super::*;
+
+/// The element types a mask can be stored in: `bool`, `f32` and `f64` hold one lane per
+/// element, and `u64` packs 64 lanes into each element.
+pub trait MaskElement: sealed::Sealed + Copy {
+    /// Number of lanes stored in each element
+    const LANES: usize;
+
+    /// Overwrites `mask` with the given lanes, which must number at most `mask.len() * LANES`.
+    fn write_lanes(mask: &mut [Self], lanes: impl Iterator<Item = bool>);
+
+    /// Reads lane `i` of `mask`. Float masks treat any nonzero value, including NaN, as set.
+    fn lane(mask: &[Self], i: usize) -> bool;
+}
+
+macro_rules! impl_mask_element {
+    ($ty:ty, $set:expr, $unset:expr) => {
+        impl MaskElement for $ty {
+            const LANES: usize = 1;
+
+            fn write_lanes(mask: &mut [Self], lanes: impl Iterator<Item = bool>) {
+                for (m, lane) in mask.iter_mut().zip(lanes) {
+                    *m = if lane { $set } else { $unset };
+                }
+            }
+
+            fn lane(mask: &[Self], i: usize) -> bool {
+                mask[i] != $unset
+            }
+        }
+    };
+}
+
+impl_mask_element!(bool, true, false);
+impl_mask_element!(f32, 1.0, 0.0);
+impl_mask_element!(f64, 1.0, 0.0);
+
+impl MaskElement for u64 {
+    const LANES: usize = 64;
+
+    fn write_lanes(mask: &mut [Self], lanes: impl Iterator<Item = bool>) {
+        let mut lanes = lanes;
+        for word in mask.iter_mut() {
+            *word = 0;
+            for (bit, lane) in (0..64).zip(lanes.by_ref()) {
+                *word |= (lane as u64) << bit;
+            }
+        }
+    }
+
+    fn lane(mask: &[Self], i: usize) -> bool {
+        (mask[i / 64] >> (i % 64)) & 1 == 1
+    }
+}
+
+/// Checks that the mask `argument` is the right length to hold one lane per element of the
+/// `reference` array
+pub(crate) fn check_mask_len<M: MaskElement>(
+    reference: (&'static str, usize), argument: &'static str, mask: &[M]
+) -> Result<(), AccelerateError> {
+    check_lengths((reference.0, reference.1.div_ceil(M::LANES)), &[(argument, mask.len())])
+}
+
+macro_rules! unary_predicate {
+    (
+    $(#[$attr:meta])*
+    $name:ident,
+    |$x:ident| $body:expr
+    ) => {
+        $(#[$attr])*
+        pub fn $name<AF: AccelerateFloat, M: MaskElement>(
+            out: &mut [M], input: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_mask_len!(input, out)?;
+            M::write_lanes(out, input.iter().map(|&$x| $body));
+            Ok(())
+        }
+    };
+}
+
+macro_rules! comparison {
+    (
+    $(#[$attr:meta])*
+    $name:ident,
+    $(#[$scalar_attr:meta])*
+    $name_scalar:ident,
+    $op:tt
+    ) => {
+        $(#[$attr])*
+        pub fn $name<AF: AccelerateFloat, M: MaskElement>(
+            out: &mut [M], a: &[AF], b: &[AF]
+        ) -> Result<(), AccelerateError> {
+            check_lengths!(a, b)?;
+            check_mask_len!(a, out)?;
+            M::write_lanes(out, a.iter().zip(b).map(|(&a, &b)| a $op b));
+            Ok(())
+        }
+        $(#[$scalar_attr])*
+        pub fn $name_scalar<AF: AccelerateFloat, M: MaskElement>(
+            out: &mut [M], a: &[AF], b: AF
+        ) -> Result<(), AccelerateError> {
+            check_mask_len!(a, out)?;
+            M::write_lanes(out, a.iter().map(|&a| a $op b));
+            Ok(())
+        }
+    };
+}
+
+// ── Classification ──
+
+unary_predicate!(
+    /// Sets the mask lane for each element which is NaN.
+    is_nan_array,
+    |x| x.is_nan());
+
+unary_predicate!(
+    /// Sets the mask lane for each element which is neither infinite nor NaN.
+    is_finite_array,
+    |x| x.is_finite());
+
+unary_predicate!(
+    /// Sets the mask lane for each element which is +/-inf.
+    is_infinite_array,
+    |x| !x.is_finite() && !x.is_nan());
+
+unary_predicate!(
+    /// Sets the mask lane for each element which is subnormal in its own precision.
+    is_subnormal_array,
+    |x| x.is_subnormal());
+
+unary_predicate!(
+    /// Sets the mask lane for each element whose sign bit is set, including -0.0 and negative NaNs.
+    signbit_array,
+    |x| x.is_sign_negative());
+
+// ── Comparison ──
+//
+// Comparisons follow IEEE 754: any comparison involving NaN is false.
+
+comparison!(
+    /// Sets the mask lane where a < b for each pair of elements.
+    lt_array,
+    /// Sets the mask lane where a < b for each element of `a` and the scalar `b`.
+    lt_scalar_array,
+    <);
+
+comparison!(
+    /// Sets the mask lane where a <= b for each pair of elements.
+    le_array,
+    /// Sets the mask lane where a <= b for each element of `a` and the scalar `b`.
+    le_scalar_array,
+    <=);
+
+comparison!(
+    /// Sets the mask lane where a == b for each pair of elements. +0.0 and -0.0 compare equal.
+    eq_array,
+    /// Sets the mask lane where a == b for each element of `a` and the scalar `b`. +0.0 and -0.0
+    /// compare equal.
+    eq_scalar_array,
+    ==);
+
+comparison!(
+    /// Sets the mask lane where a != b for each pair of elements, including where either is NaN.
+    ne_array,
+    /// Sets the mask lane where a != b for each element of `a` and the scalar `b`, including
+    /// where either is NaN.
+    ne_scalar_array,
+    !=);
+
+comparison!(
+    /// Sets the mask lane where a > b for each pair of elements.
+    gt_array,
+    /// Sets the mask lane where a > b for each element of `a` and the scalar `b`.
+    gt_scalar_array,
+    >);
+
+comparison!(
+    /// Sets the mask lane where a >= b for each pair of elements.
+    ge_array,
+    /// Sets the mask lane where a >= b for each element of `a` and the scalar `b`.
+    ge_scalar_array,
+    >=);
+
+// ── Selection ──
+
+/// Chooses between the elements of `a` and `b` according to `mask`, writing a where the mask is
+/// set and b elsewhere to `out`.
+///
+/// Combined with the masked variants of the unary functions, such as
+/// [`log1p_array_masked`](crate::exponential::log1p_array_masked), this allows piecewise
+/// functions to be evaluated without branching on each element.
+///
+/// # Example
+///
+/// ```rust
+/// use vforce::mask::{lt_scalar_array, select_array};
+/// let a: Vec<f64> = vec![1.0, 2.0, 3.0];
+/// let b: Vec<f64> = vec![-1.0, -2.0, -3.0];
+/// let mut mask = vec![false; 3];
+/// lt_scalar_array(&mut mask, &a, 2.5).unwrap();
+///
+/// let mut out = vec![0.0f64; 3];
+/// select_array(&mut out, &mask, &a, &b).unwrap();
+/// assert_eq!(out, vec![1.0, 2.0, -3.0]);
+/// ```
+pub fn select_array<AF: AccelerateFloat, M: MaskElement>(
+    out: &mut [AF], mask: &[M], a: &[AF], b: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(a, b, out)?;
+    check_mask_len!(a, mask)?;
+    for (i, (o, (&a, &b))) in out.iter_mut().zip(a.iter().zip(b)).enumerate() {
+        *o = if M::lane(mask, i) { a } else { b };
+    }
+    Ok(())
+}
+
+/// Chooses between the elements of `a` and `b` according to `mask`, keeping a where the mask is
+/// set and overwriting `a` with b elsewhere.
+pub fn select_array_in_place<AF: AccelerateFloat, M: MaskElement>(
+    a: &mut [AF], mask: &[M], b: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(a, b)?;
+    check_mask_len!(a, mask)?;
+    for (i, (a, &b)) in a.iter_mut().zip(b).enumerate() {
+        if !M::lane(mask, i) {
+            *a = b;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,604 @@
+//! Reading and writing of NumPy `.npy` files and `.npz` archives of float32, float64, complex64
+//! and complex128 arrays
+//!
+//! Arrays may be stored in C or Fortran order and in either byte order, and are always written
+//! little-endian. `.npz` archives are read and written uncompressed, as by `numpy.savez`; the
+//! entries of archives written by `numpy.savez_compressed` are reported as unsupported.
+//!
+//! ```rust,no_run
+//! use vforce::exponential::exp_array;
+//! use vforce::npy::NpyArray;
+//!
+//! let input = NpyArray::<f64>::from_file("input.npy").unwrap();
+//! let output = input.map_array(exp_array).unwrap();
+//! output.to_file("output.npy").unwrap();
+//! ```
+use super::*;
+use std::borrow::ToOwned;
+use std::fs;
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+use std::{format, vec};
+
+/// The errors returned when reading or writing `.npy` files and `.npz` archives
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NpyError {
+    /// Reading or writing the underlying file failed
+    Io(io::Error),
+    /// The data is not a valid `.npy` file or `.npz` archive
+    Format(String),
+    /// The array is stored with a different element type than the one requested
+    Dtype { expected: &'static str, found: String },
+    /// The data uses a feature this module does not support, such as a compressed archive entry
+    Unsupported(String),
+    /// The requested array is not in the `.npz` archive
+    MissingArray(String),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "NpyError::Io - {}", e),
+            Self::Format(message) => write!(f, "NpyError::Format - invalid .npy or .npz data: {}", message),
+            Self::Dtype { expected, found } => {
+                write!(f, "NpyError::Dtype - expected an array of {}, found dtype {}", expected, found)
+            }
+            Self::Unsupported(message) => write!(f, "NpyError::Unsupported - {}", message),
+            Self::MissingArray(name) => write!(f, "NpyError::MissingArray - the archive has no array named {}", name),
+        }
+    }
+}
+
+impl core::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+fn format_error(message: &str) -> NpyError {
+    NpyError::Format(message.to_owned())
+}
+
+/// The element types an `.npy` array can hold: `f32`, `f64`, and `AccelerateComplex` of either,
+/// stored as complex64 and complex128
+pub trait NpyElement: sealed::Sealed + Sized {
+    /// The NumPy type code without its byte order, such as `f8`
+    const DTYPE: &'static str;
+    /// Size of one element in bytes
+    const SIZE: usize;
+    /// Zero in the element type
+    const ZERO: Self;
+
+    /// Decodes one element from exactly `SIZE` bytes.
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+    /// Appends the little-endian encoding of the element to `out`.
+    fn write_bytes(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_float {
+    ($ty:ty, $dtype:literal, $complex_dtype:literal) => {
+        impl NpyElement for $ty {
+            const DTYPE: &'static str = $dtype;
+            const SIZE: usize = size_of::<$ty>();
+            const ZERO: Self = 0.0;
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                if big_endian { <$ty>::from_be_bytes(bytes) } else { <$ty>::from_le_bytes(bytes) }
+            }
+
+            fn write_bytes(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl NpyElement for AccelerateComplex<$ty> {
+            const DTYPE: &'static str = $complex_dtype;
+            const SIZE: usize = 2 * size_of::<$ty>();
+            const ZERO: Self = AccelerateComplex::new(0.0, 0.0);
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let (real, imaginary) = bytes.split_at(size_of::<$ty>());
+                AccelerateComplex::new(<$ty>::from_bytes(real, big_endian), <$ty>::from_bytes(imaginary, big_endian))
+            }
+
+            fn write_bytes(&self, out: &mut Vec<u8>) {
+                let (real, imaginary) = self.unpack();
+                real.write_bytes(out);
+                imaginary.write_bytes(out);
+            }
+        }
+    };
+}
+
+impl_npy_float!(f32, "f4", "c8");
+impl_npy_float!(f64, "f8", "c16");
+
+/// An n-dimensional array read from or to be written to an `.npy` file
+#[derive(Debug)]
+pub struct NpyArray<T> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    fortran_order: bool,
+}
+
+impl<T: NpyElement> NpyArray<T> {
+    /// Creates an array of the given shape from `data`, which holds its elements in C order, or
+    /// in Fortran order if `fortran_order` is set.
+    pub fn new(data: Vec<T>, shape: Vec<usize>, fortran_order: bool) -> Result<Self, NpyError> {
+        let len: usize = shape.iter().product();
+        if len != data.len() {
+            return Err(NpyError::Format(format!("shape {:?} holds {} elements, got {}", shape, len, data.len())));
+        }
+        Ok(Self { data, shape, fortran_order })
+    }
+
+    /// Creates a one-dimensional array from `data`.
+    pub fn from_vec(data: Vec<T>) -> Self {
+        let shape = vec![data.len()];
+        Self { data, shape, fortran_order: false }
+    }
+
+    /// The length of each dimension
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Whether the elements are stored in Fortran (column-major) rather than C order
+    pub fn fortran_order(&self) -> bool {
+        self.fortran_order
+    }
+
+    /// The elements in storage order
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// The elements in storage order, mutably, for use with the `_in_place` functions
+    pub fn data_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Consumes the array, returning its elements in storage order.
+    pub fn into_data(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Applies an elementwise array function such as
+    /// [`exp_array`](crate::exponential::exp_array) or
+    /// [`cosisin_array`](crate::trig::cosisin_array), returning the results in a new array of
+    /// the same shape and order.
+    pub fn map_array<U: NpyElement>(
+        &self, f: impl FnOnce(&mut [U], &[T]) -> Result<(), AccelerateError>
+    ) -> Result<NpyArray<U>, AccelerateError> {
+        let mut out: Vec<U> = (0..self.data.len()).map(|_| U::ZERO).collect();
+        f(&mut out, &self.data)?;
+        Ok(NpyArray { data: out, shape: self.shape.clone(), fortran_order: self.fortran_order })
+    }
+
+    /// Reads an array from the bytes of an `.npy` file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, NpyError> {
+        let header = Header::parse(bytes)?;
+        let Some((order, dtype)) = header.descr.split_at_checked(1) else {
+            return Err(NpyError::Format(format!("malformed descr {:?}", header.descr)));
+        };
+        let big_endian = match order {
+            "<" | "=" | "|" => false,
+            ">" => true,
+            _ => return Err(NpyError::Dtype { expected: T::DTYPE, found: header.descr }),
+        };
+        if dtype != T::DTYPE {
+            return Err(NpyError::Dtype { expected: T::DTYPE, found: header.descr });
+        }
+        let size = header.shape.iter()
+            .try_fold(T::SIZE, |size, &dim| size.checked_mul(dim))
+            .ok_or_else(|| NpyError::Format(format!("shape {:?} is too large", header.shape)))?;
+        let len = size / T::SIZE;
+        let body = bytes.get(header.data_start..).unwrap_or_default();
+        if body.len() < size {
+            return Err(NpyError::Format(format!("expected {} bytes of data, found {}", size, body.len())));
+        }
+        let data = body.chunks_exact(T::SIZE).take(len).map(|b| T::from_bytes(b, big_endian)).collect();
+        Ok(Self { data, shape: header.shape, fortran_order: header.fortran_order })
+    }
+
+    /// Reads an array from an `.npy` stream.
+    pub fn read(mut reader: impl Read) -> Result<Self, NpyError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Reads an array from the `.npy` file at `path`.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, NpyError> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Encodes the array as the bytes of an `.npy` file.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let dims: Vec<String> = self.shape.iter().map(ToString::to_string).collect();
+        let shape = match &dims[..] {
+            [dim] => format!("({},)", dim),
+            dims => format!("({})", dims.join(", ")),
+        };
+        let fortran_order = if self.fortran_order { "True" } else { "False" };
+        let mut header = format!("{{'descr': '<{}', 'fortran_order': {}, 'shape': {}, }}", T::DTYPE, fortran_order, shape);
+
+        // the header is padded with spaces and ended with a newline, so that the data starts on
+        // a 64-byte boundary, and uses a 4-byte length in version 2 if it needs one
+        let version: u8 = if header.len() + 11 <= 65536 - 64 { 1 } else { 2 };
+        let prefix = if version == 1 { 10 } else { 12 };
+        let unpadded = prefix + header.len() + 1;
+        header.extend(core::iter::repeat_n(' ', unpadded.next_multiple_of(64) - unpadded));
+        header.push('\n');
+
+        let mut out = Vec::with_capacity(prefix + header.len() + self.data.len() * T::SIZE);
+        out.extend_from_slice(b"\x93NUMPY");
+        out.extend_from_slice(&[version, 0]);
+        if version == 1 {
+            out.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        } else {
+            out.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        }
+        out.extend_from_slice(header.as_bytes());
+        for x in &self.data {
+            x.write_bytes(&mut out);
+        }
+        out
+    }
+
+    /// Writes the array to an `.npy` stream.
+    pub fn write(&self, mut writer: impl Write) -> Result<(), NpyError> {
+        writer.write_all(&self.to_bytes())?;
+        Ok(())
+    }
+
+    /// Writes the array to an `.npy` file at `path`, replacing any existing file.
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<(), NpyError> {
+        fs::write(path, self.to_bytes())?;
+        Ok(())
+    }
+}
+
+/// The fields of an `.npy` header
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+    data_start: usize,
+}
+
+impl Header {
+    fn parse(bytes: &[u8]) -> Result<Self, NpyError> {
+        if bytes.len() < 10 || &bytes[..6] != b"\x93NUMPY" {
+            return Err(format_error("missing .npy magic string"));
+        }
+        let (len, start) = match bytes[6] {
+            1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+            2 | 3 if bytes.len() >= 12 => (u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize, 12),
+            v => return Err(NpyError::Unsupported(format!(".npy format version {}", v))),
+        };
+        let header = bytes.get(start..start + len).ok_or_else(|| format_error("truncated header"))?;
+        let header = core::str::from_utf8(header).map_err(|_| format_error("header is not valid text"))?;
+
+        let descr = value(header, "descr")
+            .ok_or_else(|| format_error("header has no descr"))?
+            .trim_matches(['\'', '"'])
+            .to_owned();
+        let fortran_order = match value(header, "fortran_order") {
+            Some("True") => true,
+            Some("False") => false,
+            _ => return Err(format_error("header has no fortran_order")),
+        };
+        let shape = value(header, "shape")
+            .ok_or_else(|| format_error("header has no shape"))?
+            .trim_matches(['(', ')'])
+            .split(',')
+            .map(str::trim)
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse().map_err(|_| NpyError::Format(format!("invalid dimension {} in shape", dim))))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { descr, fortran_order, shape, data_start: start + len })
+    }
+}
+
+/// Returns the text of the value of `key` in the header dictionary.
+fn value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}':", key))? + key.len() + 3;
+    let rest = header[start..].trim_start();
+    let end = if rest.starts_with('(') { rest.find(')')? + 1 } else { rest.find([',', '}'])? };
+    Some(rest[..end].trim())
+}
+
+// ── .npz archives ──
+
+const LOCAL_HEADER: u32 = 0x04034b50;
+const CENTRAL_HEADER: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY: u32 = 0x06064b50;
+const ZIP64_LOCATOR: u32 = 0x07064b50;
+/// Placeholder for sizes and offsets which are stored in the Zip64 extra field
+const ZIP64_MARKER: u32 = u32::MAX;
+/// The DOS date of 1980-01-01, the earliest a zip entry can carry
+const DOS_EPOCH: u16 = 0x21;
+
+/// Computes the CRC-32 checksum used by zip archives.
+fn crc32(bytes: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+        while i < 256 {
+            let mut c = i as u32;
+            let mut k = 0;
+            while k < 8 {
+                c = if c & 1 == 1 { 0xedb88320 ^ (c >> 1) } else { c >> 1 };
+                k += 1;
+            }
+            table[i] = c;
+            i += 1;
+        }
+        table
+    };
+    !bytes.iter().fold(!0u32, |c, &b| TABLE[((c ^ b as u32) & 0xff) as usize] ^ (c >> 8))
+}
+
+fn u16_at(bytes: &[u8], at: usize) -> Result<u16, NpyError> {
+    bytes.get(at..at + 2).map(|b| u16::from_le_bytes(b.try_into().unwrap())).ok_or_else(|| format_error("truncated archive"))
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> Result<u32, NpyError> {
+    bytes.get(at..at + 4).map(|b| u32::from_le_bytes(b.try_into().unwrap())).ok_or_else(|| format_error("truncated archive"))
+}
+
+fn u64_at(bytes: &[u8], at: usize) -> Result<u64, NpyError> {
+    bytes.get(at..at + 8).map(|b| u64::from_le_bytes(b.try_into().unwrap())).ok_or_else(|| format_error("truncated archive"))
+}
+
+/// An array stored in an archive
+struct Entry {
+    name: String,
+    compressed: bool,
+    crc: u32,
+    size: u64,
+    header_offset: u64,
+}
+
+/// Reads the arrays of an `.npz` archive by name
+pub struct NpzReader<R> {
+    reader: R,
+    entries: Vec<Entry>,
+}
+
+impl NpzReader<fs::File> {
+    /// Opens the `.npz` archive at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, NpyError> {
+        Self::new(fs::File::open(path)?)
+    }
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    /// Reads the directory of the archive in `reader`.
+    pub fn new(mut reader: R) -> Result<Self, NpyError> {
+        // the end of central directory record lies within the last 22 bytes plus the longest
+        // possible comment
+        let len = reader.seek(SeekFrom::End(0))?;
+        let tail_start = len.saturating_sub(22 + u16::MAX as u64);
+        reader.seek(SeekFrom::Start(tail_start))?;
+        let mut tail = Vec::new();
+        reader.read_to_end(&mut tail)?;
+        let end = (0..tail.len().saturating_sub(21))
+            .rev()
+            .find(|&i| u32_at(&tail, i).ok() == Some(END_OF_CENTRAL_DIRECTORY))
+            .ok_or_else(|| format_error("not a zip archive"))?;
+
+        let mut count = u16_at(&tail, end + 10)? as u64;
+        let mut directory_size = u32_at(&tail, end + 12)? as u64;
+        let mut directory_offset = u32_at(&tail, end + 16)? as u64;
+        if count == u16::MAX as u64 || directory_offset == ZIP64_MARKER as u64 {
+            if end < 20 || u32_at(&tail, end - 20)? != ZIP64_LOCATOR {
+                return Err(format_error("missing Zip64 end of central directory locator"));
+            }
+            let mut record = [0u8; 56];
+            reader.seek(SeekFrom::Start(u64_at(&tail, end - 12)?))?;
+            reader.read_exact(&mut record)?;
+            if u32_at(&record, 0)? != ZIP64_END_OF_CENTRAL_DIRECTORY {
+                return Err(format_error("invalid Zip64 end of central directory record"));
+            }
+            count = u64_at(&record, 32)?;
+            directory_size = u64_at(&record, 40)?;
+            directory_offset = u64_at(&record, 48)?;
+        }
+
+        let mut directory = vec![0u8; directory_size as usize];
+        reader.seek(SeekFrom::Start(directory_offset))?;
+        reader.read_exact(&mut directory)?;
+        let mut entries = Vec::new();
+        let mut at = 0;
+        for _ in 0..count {
+            if u32_at(&directory, at)? != CENTRAL_HEADER {
+                return Err(format_error("invalid central directory entry"));
+            }
+            let method = u16_at(&directory, at + 10)?;
+            let crc = u32_at(&directory, at + 16)?;
+            let compressed_size = u32_at(&directory, at + 20)?;
+            let mut size = u32_at(&directory, at + 24)? as u64;
+            let name_len = u16_at(&directory, at + 28)? as usize;
+            let extra_len = u16_at(&directory, at + 30)? as usize;
+            let comment_len = u16_at(&directory, at + 32)? as usize;
+            let mut header_offset = u32_at(&directory, at + 42)? as u64;
+            let name = directory.get(at + 46..at + 46 + name_len).ok_or_else(|| format_error("truncated archive"))?;
+            let name = String::from_utf8_lossy(name).into_owned();
+
+            // the Zip64 extra field holds, in order, whichever of the uncompressed size,
+            // compressed size and header offset did not fit in 32 bits
+            let mut extra = at + 46 + name_len;
+            let extra_end = extra + extra_len;
+            while extra + 4 <= extra_end {
+                let (id, field_len) = (u16_at(&directory, extra)?, u16_at(&directory, extra + 2)? as usize);
+                if id == 1 {
+                    let mut field = extra + 4;
+                    if size == ZIP64_MARKER as u64 {
+                        size = u64_at(&directory, field)?;
+                        field += 8;
+                    }
+                    if compressed_size == ZIP64_MARKER {
+                        field += 8;
+                    }
+                    if header_offset == ZIP64_MARKER as u64 {
+                        header_offset = u64_at(&directory, field)?;
+                    }
+                }
+                extra += 4 + field_len;
+            }
+
+            let name = name.strip_suffix(".npy").unwrap_or(&name).to_owned();
+            entries.push(Entry { name, compressed: method != 0, crc, size, header_offset });
+            at = extra_end + comment_len;
+        }
+        Ok(Self { reader, entries })
+    }
+
+    /// The names of the arrays in the archive, without their `.npy` extension
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|e| e.name.as_str())
+    }
+
+    /// Reads the array named `name`.
+    pub fn array<T: NpyElement>(&mut self, name: &str) -> Result<NpyArray<T>, NpyError> {
+        let entry = self.entries.iter()
+            .find(|e| e.name == name)
+            .ok_or_else(|| NpyError::MissingArray(name.to_owned()))?;
+        if entry.compressed {
+            return Err(NpyError::Unsupported(format!("array {} is compressed", name)));
+        }
+
+        let mut local = [0u8; 30];
+        self.reader.seek(SeekFrom::Start(entry.header_offset))?;
+        self.reader.read_exact(&mut local)?;
+        if u32_at(&local, 0)? != LOCAL_HEADER {
+            return Err(format_error("invalid local file header"));
+        }
+        let skip = u16_at(&local, 26)? as i64 + u16_at(&local, 28)? as i64;
+        self.reader.seek(SeekFrom::Current(skip))?;
+        let mut bytes = vec![0u8; entry.size as usize];
+        self.reader.read_exact(&mut bytes)?;
+        if crc32(&bytes) != entry.crc {
+            return Err(NpyError::Format(format!("checksum mismatch in array {}", name)));
+        }
+        NpyArray::from_bytes(&bytes)
+    }
+}
+
+/// An entry written to an archive, remembered for the central directory
+struct WrittenEntry {
+    name: String,
+    crc: u32,
+    size: u32,
+    header_offset: u32,
+}
+
+/// Writes arrays to an uncompressed `.npz` archive, as `numpy.savez` does
+pub struct NpzWriter<W: Write> {
+    writer: W,
+    entries: Vec<WrittenEntry>,
+    offset: u64,
+}
+
+impl NpzWriter<BufWriter<fs::File>> {
+    /// Creates an `.npz` archive at `path`, replacing any existing file.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, NpyError> {
+        Ok(Self::new(BufWriter::new(fs::File::create(path)?)))
+    }
+}
+
+impl<W: Write> NpzWriter<W> {
+    /// Starts an archive written to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, entries: Vec::new(), offset: 0 }
+    }
+
+    /// Returns the offset after `len` more bytes, which must still fit the 32-bit fields of a
+    /// zip archive without Zip64.
+    fn advance(&self, len: usize) -> Result<u32, NpyError> {
+        u32::try_from(self.offset + len as u64)
+            .map_err(|_| NpyError::Unsupported("archives larger than 4 GiB cannot be written".to_owned()))
+    }
+
+    /// Adds `array` to the archive under `name`, which is stored as `name.npy`.
+    pub fn add<T: NpyElement>(&mut self, name: &str, array: &NpyArray<T>) -> Result<(), NpyError> {
+        let file_name = format!("{}.npy", name);
+        let bytes = array.to_bytes();
+        let header_offset = self.advance(0)?;
+        let size = u32::try_from(bytes.len())
+            .map_err(|_| NpyError::Unsupported("arrays larger than 4 GiB cannot be archived".to_owned()))?;
+        let crc = crc32(&bytes);
+
+        let mut header = Vec::with_capacity(30 + file_name.len());
+        header.extend_from_slice(&LOCAL_HEADER.to_le_bytes());
+        // version needed, flags, stored method and DOS time
+        header.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0]);
+        header.extend_from_slice(&DOS_EPOCH.to_le_bytes());
+        header.extend_from_slice(&crc.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&(file_name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(file_name.as_bytes());
+        self.advance(header.len() + bytes.len())?;
+
+        self.writer.write_all(&header)?;
+        self.writer.write_all(&bytes)?;
+        self.offset += (header.len() + bytes.len()) as u64;
+        self.entries.push(WrittenEntry { name: file_name, crc, size, header_offset });
+        Ok(())
+    }
+
+    /// Writes the archive directory, returning the underlying writer.
+    pub fn finish(mut self) -> Result<W, NpyError> {
+        let directory_offset = self.advance(0)?;
+        let mut directory = Vec::new();
+        for entry in &self.entries {
+            directory.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+            // version made by, version needed, flags, stored method and DOS time
+            directory.extend_from_slice(&[20, 0, 20, 0, 0, 0, 0, 0, 0, 0]);
+            directory.extend_from_slice(&DOS_EPOCH.to_le_bytes());
+            directory.extend_from_slice(&entry.crc.to_le_bytes());
+            directory.extend_from_slice(&entry.size.to_le_bytes());
+            directory.extend_from_slice(&entry.size.to_le_bytes());
+            directory.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            // extra field, comment, disk number, and internal and external attributes
+            directory.extend_from_slice(&[0; 12]);
+            directory.extend_from_slice(&entry.header_offset.to_le_bytes());
+            directory.extend_from_slice(entry.name.as_bytes());
+        }
+        self.advance(directory.len())?;
+        let count = u16::try_from(self.entries.len())
+            .map_err(|_| NpyError::Unsupported("archives of more than 65535 arrays cannot be written".to_owned()))?;
+
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        end.extend_from_slice(&[0; 4]);
+        end.extend_from_slice(&count.to_le_bytes());
+        end.extend_from_slice(&count.to_le_bytes());
+        end.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+        end.extend_from_slice(&directory_offset.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());
+
+        self.writer.write_all(&directory)?;
+        self.writer.write_all(&end)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
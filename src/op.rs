@@ -0,0 +1,195 @@
+//! Runtime lookup of the unary and binary array functions by name, for applying operations
+//! chosen from configuration
+//!
+//! ```rust
+//! use vforce::op::Op;
+//! let op: Op = "atan2".parse().unwrap();
+//! assert_eq!(op.arity(), 2);
+//!
+//! let y: Vec<f64> = vec![1.0, -1.0];
+//! let x: Vec<f64> = vec![0.0, 0.0];
+//! let mut out = vec![0.0f64; 2];
+//! op.apply(&mut out, &[&y, &x]).unwrap();
+//! assert_eq!(out, vec![core::f64::consts::FRAC_PI_2, -core::f64::consts::FRAC_PI_2]);
+//! ```
+use super::*;
+use crate::domain::{BinaryDomain, Domain};
+use core::str::FromStr;
+
+/// The error returned when parsing a string which names no operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOpError;
+
+impl Display for ParseOpError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ParseOpError - the string does not name a vforce operation")
+    }
+}
+
+impl core::error::Error for ParseOpError {}
+
+macro_rules! define_ops {
+    (
+    unary { $($unary:ident => $unary_name:literal, $unary_fn:path, $domain:expr;)* }
+    binary { $($binary:ident => $binary_name:literal, $binary_fn:path, $binary_domain:expr;)* }
+    ) => {
+        /// The unary and binary array functions, each named by its function name without the
+        /// `_array` suffix
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum Op {
+            $(#[doc = concat!("[`", stringify!($unary_fn), "`]")] $unary,)*
+            $(#[doc = concat!("[`", stringify!($binary_fn), "`]")] $binary,)*
+        }
+
+        impl Op {
+            /// Every operation, unary operations first
+            pub const ALL: &'static [Op] = &[$(Self::$unary,)* $(Self::$binary,)*];
+
+            /// Returns the name the operation is parsed from and displayed as.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$unary => $unary_name,)*
+                    $(Self::$binary => $binary_name,)*
+                }
+            }
+
+            /// Returns the number of input arrays the operation takes.
+            pub fn arity(self) -> usize {
+                match self {
+                    $(Self::$unary => 1,)*
+                    $(Self::$binary => 2,)*
+                }
+            }
+
+            /// Returns the inputs for which a unary operation does not produce NaN from a non-NaN
+            /// input, or `None` if it is defined everywhere or is binary, for which see
+            /// [`binary_domain`](Self::binary_domain).
+            pub fn domain(self) -> Option<Domain> {
+                match self {
+                    $(Self::$unary => $domain,)*
+                    $(Self::$binary => None,)*
+                }
+            }
+
+            /// Returns the pairs of inputs for which a binary operation does not produce NaN from
+            /// non-NaN inputs, or `None` if it is defined everywhere or is unary.
+            pub fn binary_domain(self) -> Option<BinaryDomain> {
+                match self {
+                    $(Self::$unary => None,)*
+                    $(Self::$binary => $binary_domain,)*
+                }
+            }
+
+            /// Applies the operation to `inputs`, which must hold one array per input, writing the
+            /// results to `out`.
+            pub fn apply<AF: AccelerateFloat>(
+                self, out: &mut [AF], inputs: &[&[AF]]
+            ) -> Result<(), AccelerateError> {
+                match (self, inputs) {
+                    $((Self::$unary, [input]) => $unary_fn(out, input),)*
+                    $((Self::$binary, [a, b]) => $binary_fn(out, a, b),)*
+                    _ => Err(AccelerateError::ArityMismatch { expected: self.arity(), got: inputs.len() }),
+                }
+            }
+        }
+
+        impl FromStr for Op {
+            type Err = ParseOpError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($unary_name => Ok(Self::$unary),)*
+                    $($binary_name => Ok(Self::$binary),)*
+                    _ => Err(ParseOpError),
+                }
+            }
+        }
+    };
+}
+
+impl Op {
+    /// Returns true if each result depends on the corresponding input elements alone, so that
+    /// arrays can be split and processed in parts, which is true of every operation except
+    /// `softmax` and `log_softmax`.
+    pub fn is_elementwise(self) -> bool {
+        !matches!(self, Self::Softmax | Self::LogSoftmax)
+    }
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+define_ops! {
+    unary {
+        Cbrt => "cbrt", crate::arithmetic::cbrt_array, None;
+        Ceil => "ceil", crate::arithmetic::ceil_array, None;
+        Floor => "floor", crate::arithmetic::floor_array, None;
+        Fabs => "fabs", crate::arithmetic::fabs_array, None;
+        Int => "int", crate::arithmetic::int_array, None;
+        Nint => "nint", crate::arithmetic::nint_array, None;
+        Rsqrt => "rsqrt", crate::arithmetic::rsqrt_array, Some(Domain::NonNegative);
+        Sqrt => "sqrt", crate::arithmetic::sqrt_array, Some(Domain::NonNegative);
+        Rec => "rec", crate::arithmetic::rec_array, None;
+        Neg => "neg", crate::arithmetic::neg_array, None;
+        Sqr => "sqr", crate::arithmetic::sqr_array, None;
+        RoundHalfAway => "round_half_away", crate::arithmetic::round_half_away_array, None;
+        RoundHalfEven => "round_half_even", crate::arithmetic::round_half_even_array, None;
+        Rint => "rint", crate::arithmetic::rint_array, None;
+        Exp => "exp", crate::exponential::exp_array, None;
+        Exp2 => "exp2", crate::exponential::exp2_array, None;
+        Expm1 => "expm1", crate::exponential::expm1_array, None;
+        Log => "log", crate::exponential::log_array, Some(Domain::NonNegative);
+        Log1p => "log1p", crate::exponential::log1p_array, Some(Domain::AtLeastMinusOne);
+        Log2 => "log2", crate::exponential::log2_array, Some(Domain::NonNegative);
+        Log10 => "log10", crate::exponential::log10_array, Some(Domain::NonNegative);
+        Logb => "logb", crate::exponential::logb_array, None;
+        Exp10 => "exp10", crate::exponential::exp10_array, None;
+        Exp2m1 => "exp2m1", crate::exponential::exp2m1_array, None;
+        Exp10m1 => "exp10m1", crate::exponential::exp10m1_array, None;
+        Softmax => "softmax", crate::exponential::softmax_array, None;
+        LogSoftmax => "log_softmax", crate::exponential::log_softmax_array, None;
+        Sin => "sin", crate::trig::sin_array, None;
+        Sinpi => "sinpi", crate::trig::sinpi_array, None;
+        Cos => "cos", crate::trig::cos_array, None;
+        Cospi => "cospi", crate::trig::cospi_array, None;
+        Tan => "tan", crate::trig::tan_array, None;
+        Tanpi => "tanpi", crate::trig::tanpi_array, None;
+        Asin => "asin", crate::trig::asin_array, Some(Domain::UnitInterval);
+        Acos => "acos", crate::trig::acos_array, Some(Domain::UnitInterval);
+        Atan => "atan", crate::trig::atan_array, None;
+        Sind => "sind", crate::trig::sind_array, None;
+        Cosd => "cosd", crate::trig::cosd_array, None;
+        Tand => "tand", crate::trig::tand_array, None;
+        Asind => "asind", crate::trig::asind_array, Some(Domain::UnitInterval);
+        Acosd => "acosd", crate::trig::acosd_array, Some(Domain::UnitInterval);
+        Atand => "atand", crate::trig::atand_array, None;
+        Sinh => "sinh", crate::hyperbolic::sinh_array, None;
+        Cosh => "cosh", crate::hyperbolic::cosh_array, None;
+        Tanh => "tanh", crate::hyperbolic::tanh_array, None;
+        Asinh => "asinh", crate::hyperbolic::asinh_array, None;
+        Acosh => "acosh", crate::hyperbolic::acosh_array, Some(Domain::AtLeastOne);
+        Atanh => "atanh", crate::hyperbolic::atanh_array, Some(Domain::UnitInterval);
+    }
+    binary {
+        Pow => "pow", crate::arithmetic::pow_array, Some(BinaryDomain::RealPower);
+        Hypot => "hypot", crate::arithmetic::hypot_array, None;
+        Rhypot => "rhypot", crate::arithmetic::rhypot_array, None;
+        Div => "div", crate::arithmetic::div_array, None;
+        Copysign => "copysign", crate::arithmetic::copysign_array, None;
+        Fmod => "fmod", crate::arithmetic::fmod_array, Some(BinaryDomain::NonZeroDivisor);
+        Remainder => "remainder", crate::arithmetic::remainder_array, Some(BinaryDomain::NonZeroDivisor);
+        Nextafter => "nextafter", crate::arithmetic::nextafter_array, None;
+        Add => "add", crate::arithmetic::add_array, None;
+        Sub => "sub", crate::arithmetic::sub_array, None;
+        Mul => "mul", crate::arithmetic::mul_array, None;
+        AbsDiff => "abs_diff", crate::arithmetic::abs_diff_array, None;
+        Min => "min", crate::arithmetic::min_array, None;
+        Max => "max", crate::arithmetic::max_array, None;
+        Atan2 => "atan2", crate::trig::atan2_array, Some(BinaryDomain::NotBothZero);
+        Atan2d => "atan2d", crate::trig::atan2d_array, Some(BinaryDomain::NotBothZero);
+    }
+}
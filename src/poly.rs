@@ -0,0 +1,190 @@
+//! Evaluation of polynomials, Chebyshev series and rational functions at each element of an array
+//!
+//! Coefficients are given lowest degree first, so that `coeffs[i]` multiplies x^i (or T_i(x) for
+//! a Chebyshev series), as in `numpy.polynomial`. Elements are evaluated a tile at a time, each
+//! step of the recurrence being applied across the whole tile so that it can be vectorized.
+//!
+//! ```rust
+//! use vforce::poly::{polyval_array, rational_eval_array};
+//!
+//! let x: Vec<f64> = vec![0.0, 1.0, 2.0];
+//! let mut out = vec![0.0f64; 3];
+//!
+//! // 1 + 2x + 3x^2
+//! polyval_array(&mut out, &x, &[1.0, 2.0, 3.0]).unwrap();
+//! assert_eq!(out, vec![1.0, 6.0, 17.0]);
+//!
+//! // (1 + x) / (1 + x^2)
+//! rational_eval_array(&mut out, &x, &[1.0, 1.0], &[1.0, 0.0, 1.0]).unwrap();
+//! assert_eq!(out, vec![1.0, 1.0, 0.6]);
+//! ```
+use super::*;
+
+/// Applies `eval` to `x` a tile at a time, writing the results to `out`.
+fn tiled<AF: AccelerateFloat>(
+    out: &mut [AF], x: &[AF], eval: impl Fn(&mut [AF], &[AF])
+) -> Result<(), AccelerateError> {
+    check_lengths!(x, out)?;
+    for (out_tile, x_tile) in out.chunks_mut(TILE).zip(x.chunks(TILE)) {
+        eval(out_tile, x_tile);
+    }
+    Ok(())
+}
+
+/// Applies `eval` to `x` a tile at a time, overwriting `x` with the results.
+fn tiled_in_place<AF: AccelerateFloat>(x: &mut [AF], eval: impl Fn(&mut [AF], &[AF])) {
+    let mut buf = [AF::ZERO; TILE];
+    for tile in x.chunks_mut(TILE) {
+        let x_tile = &mut buf[..tile.len()];
+        x_tile.copy_from_slice(tile);
+        eval(tile, x_tile);
+    }
+}
+
+/// Evaluates the polynomial by Horner's method at each element of `x`.
+fn horner<AF: AccelerateFloat>(out: &mut [AF], x: &[AF], coeffs: &[AF]) {
+    let Some((&last, rest)) = coeffs.split_last() else {
+        out.fill(AF::ZERO);
+        return;
+    };
+    out.fill(last);
+    for &c in rest.iter().rev() {
+        for (o, &x) in out.iter_mut().zip(x) {
+            *o = o.mul_add(x, c);
+        }
+    }
+}
+
+/// Evaluates the polynomial by Estrin's scheme at each element of `x`, which is at most one tile.
+fn estrin<AF: AccelerateFloat>(out: &mut [AF], x: &[AF], coeffs: &[AF]) {
+    match *coeffs {
+        [] => out.fill(AF::ZERO),
+        [c] => out.fill(c),
+        [c0, c1] => {
+            for (o, &x) in out.iter_mut().zip(x) {
+                *o = c1.mul_add(x, c0);
+            }
+        }
+        _ => {
+            // p(x) = lo(x) + x^m hi(x), with m the largest power of two below the number of
+            // coefficients, so that lo and hi can be evaluated independently
+            let k = (coeffs.len() - 1).ilog2();
+            let (lo, hi) = coeffs.split_at(1 << k);
+            estrin(out, x, lo);
+            let mut hi_out = [AF::ZERO; TILE];
+            let hi_out = &mut hi_out[..x.len()];
+            estrin(hi_out, x, hi);
+            for ((o, &h), &x) in out.iter_mut().zip(hi_out.iter()).zip(x) {
+                let mut power = x;
+                for _ in 0..k {
+                    power = power * power;
+                }
+                *o = h.mul_add(power, *o);
+            }
+        }
+    }
+}
+
+/// Evaluates the Chebyshev series by Clenshaw's recurrence at each element of `x`, which is at
+/// most one tile.
+fn clenshaw<AF: AccelerateFloat>(out: &mut [AF], x: &[AF], coeffs: &[AF]) {
+    let Some((&c0, rest)) = coeffs.split_first() else {
+        out.fill(AF::ZERO);
+        return;
+    };
+    // b1 and b2 hold b_{k+1} and b_{k+2} of the recurrence b_k = c_k + 2x b_{k+1} - b_{k+2}
+    let mut b1 = [AF::ZERO; TILE];
+    let mut b2 = [AF::ZERO; TILE];
+    let (b1, b2) = (&mut b1[..x.len()], &mut b2[..x.len()]);
+    for &c in rest.iter().rev() {
+        for ((b1, b2), &x) in b1.iter_mut().zip(b2.iter_mut()).zip(x) {
+            let b0 = (x + x).mul_add(*b1, c - *b2);
+            *b2 = *b1;
+            *b1 = b0;
+        }
+    }
+    for ((o, (&b1, &b2)), &x) in out.iter_mut().zip(b1.iter().zip(b2.iter())).zip(x) {
+        *o = x.mul_add(b1, c0 - b2);
+    }
+}
+
+/// Evaluates numerator / denominator at each element of `x`, which is at most one tile.
+fn rational<AF: AccelerateFloat>(out: &mut [AF], x: &[AF], numerator: &[AF], denominator: &[AF]) {
+    let mut den = [AF::ZERO; TILE];
+    let den = &mut den[..x.len()];
+    horner(out, x, numerator);
+    horner(den, x, denominator);
+    for (o, &d) in out.iter_mut().zip(den.iter()) {
+        *o = *o / d;
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs`, lowest degree first, at each element of
+/// `x` by Horner's method, writing the results to `out`.
+///
+/// An empty `coeffs` is the zero polynomial.
+pub fn polyval_array<AF: AccelerateFloat>(
+    out: &mut [AF], x: &[AF], coeffs: &[AF]
+) -> Result<(), AccelerateError> {
+    tiled(out, x, |out, x| horner(out, x, coeffs))
+}
+
+/// Evaluates the polynomial with coefficients `coeffs`, lowest degree first, at each element of
+/// `x` by Horner's method, overwriting `x` with the results.
+pub fn polyval_array_in_place<AF: AccelerateFloat>(x: &mut [AF], coeffs: &[AF]) {
+    tiled_in_place(x, |out, x| horner(out, x, coeffs))
+}
+
+/// Evaluates the polynomial with coefficients `coeffs`, lowest degree first, at each element of
+/// `x` by Estrin's scheme, writing the results to `out`.
+///
+/// Estrin's scheme splits the polynomial into halves which are evaluated independently, which
+/// shortens the chain of dependent operations for high degrees at the cost of computing powers
+/// of `x`. Results may differ from those of [`polyval_array`] in the last bits.
+pub fn polyval_estrin_array<AF: AccelerateFloat>(
+    out: &mut [AF], x: &[AF], coeffs: &[AF]
+) -> Result<(), AccelerateError> {
+    tiled(out, x, |out, x| estrin(out, x, coeffs))
+}
+
+/// Evaluates the polynomial with coefficients `coeffs`, lowest degree first, at each element of
+/// `x` by Estrin's scheme, overwriting `x` with the results.
+pub fn polyval_estrin_array_in_place<AF: AccelerateFloat>(x: &mut [AF], coeffs: &[AF]) {
+    tiled_in_place(x, |out, x| estrin(out, x, coeffs))
+}
+
+/// Evaluates the Chebyshev series sum(coeffs[i] * T_i(x)) at each element of `x` by Clenshaw's
+/// recurrence, writing the results to `out`.
+///
+/// The series is evaluated as given at any `x`; a series fitted on an interval other than
+/// [-1, 1] needs `x` mapped onto [-1, 1] first.
+pub fn chebyshev_eval_array<AF: AccelerateFloat>(
+    out: &mut [AF], x: &[AF], coeffs: &[AF]
+) -> Result<(), AccelerateError> {
+    tiled(out, x, |out, x| clenshaw(out, x, coeffs))
+}
+
+/// Evaluates the Chebyshev series sum(coeffs[i] * T_i(x)) at each element of `x` by Clenshaw's
+/// recurrence, overwriting `x` with the results.
+pub fn chebyshev_eval_array_in_place<AF: AccelerateFloat>(x: &mut [AF], coeffs: &[AF]) {
+    tiled_in_place(x, |out, x| clenshaw(out, x, coeffs))
+}
+
+/// Evaluates the rational function p(x) / q(x) at each element of `x`, where p and q are the
+/// polynomials with coefficients `numerator` and `denominator`, lowest degree first, writing the
+/// results to `out`.
+///
+/// Both polynomials are evaluated by Horner's method. Where q(x) is zero the result is infinite
+/// or NaN, as for division.
+pub fn rational_eval_array<AF: AccelerateFloat>(
+    out: &mut [AF], x: &[AF], numerator: &[AF], denominator: &[AF]
+) -> Result<(), AccelerateError> {
+    tiled(out, x, |out, x| rational(out, x, numerator, denominator))
+}
+
+/// Evaluates the rational function p(x) / q(x) at each element of `x`, where p and q are the
+/// polynomials with coefficients `numerator` and `denominator`, lowest degree first, overwriting
+/// `x` with the results.
+pub fn rational_eval_array_in_place<AF: AccelerateFloat>(x: &mut [AF], numerator: &[AF], denominator: &[AF]) {
+    tiled_in_place(x, |out, x| rational(out, x, numerator, denominator))
+}
@@ -0,0 +1,134 @@
+//! Python extension module exposing every [`Op`] as a NumPy ufunc-like callable
+//!
+//! Built with maturin from the `pyproject.toml` at the root of the repository:
+//!
+//! ```python
+//! import numpy as np
+//! import vforce
+//!
+//! x = np.linspace(0.0, 1.0, 1000)
+//! y = vforce.sin(x)
+//! vforce.pow(x, y, out=y)
+//! ```
+//!
+//! Each callable takes its input arrays positionally and an optional `out` array, and returns
+//! the output array. Inputs which are already C-contiguous float32 or float64 arrays are read
+//! without copying, and the GIL is released while the results are computed by the same
+//! functions as [`Op::apply`], so results are bit-identical to those of the Rust API. The
+//! computation is in float32 when every input is a float32 array, or when `out` is one, and in
+//! float64 otherwise, converting other inputs as `numpy.ascontiguousarray` does.
+//!
+//! As the crate has no implementation of its own of the VForce functions, the module can only be
+//! built on MacOS.
+use super::*;
+use crate::op::Op;
+use std::format;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use numpy::{Element, PyArrayDyn, PyArrayMethods, PyUntypedArrayMethods};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+/// A vforce operation, called like a NumPy ufunc
+#[pyclass(frozen, name = "ufunc", module = "vforce")]
+pub struct Ufunc {
+    op: Op,
+}
+
+#[pymethods]
+impl Ufunc {
+    #[pyo3(signature = (*args, out = None))]
+    fn __call__<'py>(
+        &self, args: &Bound<'py, PyTuple>, out: Option<Bound<'py, PyAny>>
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if args.len() != self.op.arity() {
+            return Err(PyTypeError::new_err(format!(
+                "{}() takes {} input arrays, got {}", self.op, self.op.arity(), args.len()
+            )));
+        }
+        let is_f32 = |array: &Bound<'py, PyAny>| array.cast::<PyArrayDyn<f32>>().is_ok();
+        let single = match &out {
+            Some(out) => is_f32(out),
+            None => args.iter().all(|arg| is_f32(&arg)),
+        };
+        if single { call::<f32>(self.op, args, out) } else { call::<f64>(self.op, args, out) }
+    }
+
+    /// The name of the operation, as in the Rust API without the `_array` suffix
+    #[getter]
+    fn __name__(&self) -> &'static str {
+        self.op.name()
+    }
+
+    /// The number of input arrays
+    #[getter]
+    fn nin(&self) -> usize {
+        self.op.arity()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<vforce.ufunc '{}'>", self.op)
+    }
+}
+
+/// Applies `op` in precision `AF`, converting the inputs and checking `out` as described in the
+/// module documentation.
+fn call<'py, AF: AccelerateFloat + Element>(
+    op: Op, args: &Bound<'py, PyTuple>, out: Option<Bound<'py, PyAny>>
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = args.py();
+    let numpy = py.import("numpy")?;
+    let dtype = numpy::dtype::<AF>(py);
+    let inputs = args.iter()
+        .map(|arg| Ok(numpy.call_method1("ascontiguousarray", (arg, &dtype))?.cast_into::<PyArrayDyn<AF>>()?))
+        .collect::<PyResult<Vec<_>>>()?;
+    let shape = inputs[0].shape().to_vec();
+    if inputs.iter().any(|input| input.shape() != shape) {
+        return Err(PyValueError::new_err(format!("{}() requires input arrays of the same shape", op)));
+    }
+
+    let out = match out {
+        Some(out) => out.cast_into::<PyArrayDyn<AF>>().map_err(|_| {
+            PyTypeError::new_err(format!("out must be a {} array", dtype))
+        })?,
+        None => PyArrayDyn::<AF>::zeros(py, shape.as_slice(), false),
+    };
+    if out.shape() != shape {
+        return Err(PyValueError::new_err(format!("out must have shape {:?}, got {:?}", shape, out.shape())));
+    }
+
+    // an input which is also the output is copied, since the two cannot be borrowed at once
+    let copies = inputs.iter()
+        .map(|input| input.is(&out).then(|| input.to_vec()).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+    let borrows = inputs.iter()
+        .zip(&copies)
+        .map(|(input, copy)| copy.is_none().then(|| input.try_readonly()).transpose())
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut out_borrow = out.try_readwrite()?;
+    let slices = borrows.iter()
+        .zip(&copies)
+        .map(|(borrow, copy)| match (borrow, copy) {
+            (Some(borrow), _) => borrow.as_slice(),
+            (None, copy) => Ok(copy.as_deref().unwrap_or_default()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let out_slice = out_borrow.as_slice_mut()
+        .map_err(|_| PyValueError::new_err("out must be a C-contiguous array"))?;
+
+    py.detach(|| op.apply(out_slice, &slices)).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    drop(out_borrow);
+    Ok(out.into_any())
+}
+
+/// Builds the `vforce` module, with one [`Ufunc`] for each [`Op`].
+#[pymodule]
+pub fn vforce(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Ufunc>()?;
+    for &op in Op::ALL {
+        m.add(op.name(), Ufunc { op })?;
+    }
+    Ok(())
+}
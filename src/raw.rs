@@ -0,0 +1,438 @@
+//! Raw-pointer forms of the array functions, for memory that is not available as a slice, such
+//! as buffers handed over from C or mapped from a file
+//!
+//! Each function takes its arrays as pointers with a shared element count `len`, and processes
+//! arrays of any length, in chunks where the backend requires it.
+//!
+//! # Aliasing
+//!
+//! An output may be exactly equal to the first input, in which case the results overwrite that
+//! input, as with the `_in_place` functions. The binary VForce functions, such as [`pow`], also
+//! allow it to equal the second input, as with the `_in_place_rhs` functions. Any other overlap
+//! between an output and an input or another output is detected and reported as
+//! `AccelerateError::Overlap` without anything being written. Inputs may overlap each other
+//! freely, so an output may also equal several identical inputs, as in `pow(p, p, p)`.
+//!
+//! # Safety
+//!
+//! Every pointer must be non-null, aligned, and valid for `len` elements: for reads if it is an
+//! input, and for reads and writes if it is an output. No reference to the memory behind an
+//! output may be live during the call.
+//!
+//! ```rust
+//! let mut buf: Vec<f64> = vec![0.0, 1.0, 2.0];
+//! let ptr = buf.as_mut_ptr();
+//!
+//! // computing in place, with `out` equal to `input`
+//! unsafe { vforce::raw::exp(ptr, ptr, 3).unwrap() };
+//! assert_eq!(buf[0], 1.0);
+//!
+//! // partially overlapping arrays are rejected
+//! let result = unsafe { vforce::raw::exp(ptr, ptr.add(1), 2) };
+//! assert!(matches!(result, Err(vforce::AccelerateError::Overlap { .. })));
+//! ```
+use super::*;
+use core::slice;
+
+/// Returns `AccelerateError::Overlap` if the arrays of `len` elements at `first` and `second`
+/// share any memory, or if either would run past the end of the address space, which no array
+/// can
+fn check_disjoint<T, U>(
+    first: *const T, first_name: &'static str, second: *const U, second_name: &'static str, len: usize
+) -> Result<(), AccelerateError> {
+    let end = |start: usize, size: usize| len.checked_mul(size).and_then(|bytes| start.checked_add(bytes));
+    let (a, b) = (first as usize, second as usize);
+    let overlap = match (end(a, size_of::<T>()), end(b, size_of::<U>())) {
+        (Some(a_end), Some(b_end)) => a < b_end && b < a_end,
+        _ => true,
+    };
+    if overlap {
+        return Err(AccelerateError::Overlap { first: first_name, second: second_name });
+    }
+    Ok(())
+}
+
+/// Applies the elementwise `in_place` to the `len` elements at `out`, which is also its first
+/// input, and `inputs`, a tile at a time. Each of `inputs` equal to `out` is copied out of the tile
+/// before it is overwritten; the others must not overlap `out`.
+///
+/// # Safety
+///
+/// As for the functions of this module.
+unsafe fn in_place_aliased<AF: AccelerateFloat, const N: usize>(
+    out: *mut AF, inputs: [*const AF; N], len: usize,
+    in_place: impl Fn(&mut [AF], [&[AF]; N]) -> Result<(), AccelerateError>
+) -> Result<(), AccelerateError> {
+    let mut copies = [[AF::ZERO; TILE]; N];
+    for offset in (0..len).step_by(TILE) {
+        let n = TILE.min(len - offset);
+        for (copy, &input) in copies.iter_mut().zip(&inputs) {
+            if input == out.cast_const() {
+                copy[..n].copy_from_slice(unsafe { slice::from_raw_parts(input.add(offset), n) });
+            }
+        }
+        let tiles: [&[AF]; N] = core::array::from_fn(|i| {
+            if inputs[i] == out.cast_const() {
+                &copies[i][..n]
+            } else {
+                unsafe { slice::from_raw_parts(inputs[i].add(offset), n) }
+            }
+        });
+        in_place(unsafe { slice::from_raw_parts_mut(out.add(offset), n) }, tiles)?;
+    }
+    Ok(())
+}
+
+/// Unifies the in-place functions which cannot fail with those which check their lengths
+trait IntoResult {
+    fn into_result(self) -> Result<(), AccelerateError>;
+}
+
+impl IntoResult for () {
+    fn into_result(self) -> Result<(), AccelerateError> {
+        Ok(())
+    }
+}
+
+impl IntoResult for Result<(), AccelerateError> {
+    fn into_result(self) -> Result<(), AccelerateError> {
+        self
+    }
+}
+
+macro_rules! raw_op {
+    (
+    $name:ident, $array:path, $in_place:path, rhs: $in_place_rhs:path,
+    ($first:ident, $second:ident),
+    ()
+    ) => {
+        #[doc = concat!("Raw-pointer form of [`", stringify!($array), "`], over `len` elements.")]
+        #[doc = ""]
+        #[doc = concat!("`out` may be equal to either or both of `", stringify!($first), "` and `", stringify!($second), "`, and must otherwise not overlap them.")]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "Every pointer must be non-null, aligned and valid for `len` elements, and no reference to"]
+        #[doc = "the memory behind `out` may be live during the call."]
+        pub unsafe fn $name<AF: AccelerateFloat>(
+            out: *mut AF, $first: *const AF, $second: *const AF, len: usize
+        ) -> Result<(), AccelerateError> {
+            if len == 0 {
+                return Ok(());
+            }
+            if out.cast_const() == $first && $second == $first {
+                unsafe { in_place_aliased(out, [$second], len, |out, [$second]| $in_place(out, $second)) }
+            } else if out.cast_const() == $first {
+                check_disjoint(out, "out", $second, stringify!($second), len)?;
+                unsafe { $in_place(slice::from_raw_parts_mut(out, len), slice::from_raw_parts($second, len)) }
+            } else if out.cast_const() == $second {
+                check_disjoint(out, "out", $first, stringify!($first), len)?;
+                unsafe { $in_place_rhs(slice::from_raw_parts($first, len), slice::from_raw_parts_mut(out, len)) }
+            } else {
+                check_disjoint(out, "out", $first, stringify!($first), len)?;
+                check_disjoint(out, "out", $second, stringify!($second), len)?;
+                unsafe {
+                    $array(
+                        slice::from_raw_parts_mut(out, len),
+                        slice::from_raw_parts($first, len),
+                        slice::from_raw_parts($second, len),
+                    )
+                }
+            }
+        }
+    };
+    (
+    $name:ident, $array:path, $in_place:path,
+    ($first:ident $(, $rest:ident)*),
+    ($($param:ident: $param_ty:ty),*)
+    ) => {
+        #[doc = concat!("Raw-pointer form of [`", stringify!($array), "`], over `len` elements.")]
+        #[doc = ""]
+        #[doc = concat!("`out` may be equal to `", stringify!($first), "`, as may any input equal to it, and must otherwise not overlap any input.")]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "Every pointer must be non-null, aligned and valid for `len` elements, and no reference to"]
+        #[doc = "the memory behind `out` may be live during the call."]
+        pub unsafe fn $name<AF: AccelerateFloat>(
+            out: *mut AF, $first: *const AF, $($rest: *const AF,)* len: usize $(, $param: $param_ty)*
+        ) -> Result<(), AccelerateError> {
+            if len == 0 {
+                return Ok(());
+            }
+            if out.cast_const() == $first {
+                $(if $rest != $first {
+                    check_disjoint(out, "out", $rest, stringify!($rest), len)?;
+                })*
+                if [$($rest),*].contains(&$first) {
+                    return unsafe {
+                        in_place_aliased(out, [$($rest),*], len, |out, [$($rest),*]| {
+                            $in_place(out, $($rest,)* $($param,)*).into_result()
+                        })
+                    };
+                }
+                unsafe {
+                    $in_place(slice::from_raw_parts_mut(out, len), $(slice::from_raw_parts($rest, len),)* $($param,)*)
+                }.into_result()
+            } else {
+                $(check_disjoint(out, "out", $rest, stringify!($rest), len)?;)*
+                check_disjoint(out, "out", $first, stringify!($first), len)?;
+                unsafe {
+                    $array(
+                        slice::from_raw_parts_mut(out, len),
+                        slice::from_raw_parts($first, len),
+                        $(slice::from_raw_parts($rest, len),)*
+                        $($param,)*
+                    )
+                }
+            }
+        }
+    };
+    (
+    $name:ident, $array:path,
+    ($a:ident: $in_place_a:path, $b:ident: $in_place_b:path),
+    $input:ident
+    ) => {
+        #[doc = concat!("Raw-pointer form of [`", stringify!($array), "`], over `len` elements.")]
+        #[doc = ""]
+        #[doc = concat!("Either `", stringify!($a), "` or `", stringify!($b), "` may be equal to `", stringify!($input), "`, and the arrays must otherwise not overlap.")]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = "Every pointer must be non-null, aligned and valid for `len` elements, and no reference to"]
+        #[doc = "the memory behind the outputs may be live during the call."]
+        pub unsafe fn $name<AF: AccelerateFloat>(
+            $a: *mut AF, $b: *mut AF, $input: *const AF, len: usize
+        ) -> Result<(), AccelerateError> {
+            if len == 0 {
+                return Ok(());
+            }
+            check_disjoint($a, stringify!($a), $b, stringify!($b), len)?;
+            if $a.cast_const() == $input {
+                unsafe { $in_place_a(slice::from_raw_parts_mut($b, len), slice::from_raw_parts_mut($a, len)) }
+            } else if $b.cast_const() == $input {
+                unsafe { $in_place_b(slice::from_raw_parts_mut($a, len), slice::from_raw_parts_mut($b, len)) }
+            } else {
+                check_disjoint($a, stringify!($a), $input, stringify!($input), len)?;
+                check_disjoint($b, stringify!($b), $input, stringify!($input), len)?;
+                unsafe {
+                    $array(
+                        slice::from_raw_parts_mut($a, len),
+                        slice::from_raw_parts_mut($b, len),
+                        slice::from_raw_parts($input, len),
+                    )
+                }
+            }
+        }
+    };
+}
+
+// ── arithmetic ──
+
+raw_op!(pow, crate::arithmetic::pow_array, crate::arithmetic::pow_array_in_place,
+    rhs: crate::arithmetic::pow_array_in_place_rhs, (bases, exponents), ());
+raw_op!(cbrt, crate::arithmetic::cbrt_array, crate::arithmetic::cbrt_array_in_place, (input), ());
+raw_op!(hypot, crate::arithmetic::hypot_array, crate::arithmetic::hypot_array_in_place, (x, y), ());
+raw_op!(hypot_scalar, crate::arithmetic::hypot_scalar_array, crate::arithmetic::hypot_scalar_array_in_place, (x), (y: AF));
+raw_op!(rhypot, crate::arithmetic::rhypot_array, crate::arithmetic::rhypot_array_in_place, (x, y), ());
+raw_op!(rhypot_scalar, crate::arithmetic::rhypot_scalar_array, crate::arithmetic::rhypot_scalar_array_in_place, (x), (y: AF));
+raw_op!(div, crate::arithmetic::div_array, crate::arithmetic::div_array_in_place,
+    rhs: crate::arithmetic::div_array_in_place_rhs, (numerator, denominator), ());
+raw_op!(copysign, crate::arithmetic::copysign_array, crate::arithmetic::copysign_array_in_place,
+    rhs: crate::arithmetic::copysign_array_in_place_rhs, (magnitude, sign), ());
+raw_op!(fmod, crate::arithmetic::fmod_array, crate::arithmetic::fmod_array_in_place,
+    rhs: crate::arithmetic::fmod_array_in_place_rhs, (numerator, denominator), ());
+raw_op!(remainder, crate::arithmetic::remainder_array, crate::arithmetic::remainder_array_in_place,
+    rhs: crate::arithmetic::remainder_array_in_place_rhs, (numerator, denominator), ());
+raw_op!(nextafter, crate::arithmetic::nextafter_array, crate::arithmetic::nextafter_array_in_place,
+    rhs: crate::arithmetic::nextafter_array_in_place_rhs, (input, direction), ());
+raw_op!(ceil, crate::arithmetic::ceil_array, crate::arithmetic::ceil_array_in_place, (input), ());
+raw_op!(floor, crate::arithmetic::floor_array, crate::arithmetic::floor_array_in_place, (input), ());
+raw_op!(fabs, crate::arithmetic::fabs_array, crate::arithmetic::fabs_array_in_place, (input), ());
+raw_op!(int, crate::arithmetic::int_array, crate::arithmetic::int_array_in_place, (input), ());
+raw_op!(nint, crate::arithmetic::nint_array, crate::arithmetic::nint_array_in_place, (input), ());
+raw_op!(rsqrt, crate::arithmetic::rsqrt_array, crate::arithmetic::rsqrt_array_in_place, (input), ());
+raw_op!(sqrt, crate::arithmetic::sqrt_array, crate::arithmetic::sqrt_array_in_place, (input), ());
+raw_op!(rec, crate::arithmetic::rec_array, crate::arithmetic::rec_array_in_place, (input), ());
+raw_op!(add, crate::arithmetic::add_array, crate::arithmetic::add_array_in_place, (a, b), ());
+raw_op!(add_scalar, crate::arithmetic::add_scalar_array, crate::arithmetic::add_scalar_array_in_place, (a), (b: AF));
+raw_op!(sub, crate::arithmetic::sub_array, crate::arithmetic::sub_array_in_place, (a, b), ());
+raw_op!(sub_scalar, crate::arithmetic::sub_scalar_array, crate::arithmetic::sub_scalar_array_in_place, (a), (b: AF));
+raw_op!(mul, crate::arithmetic::mul_array, crate::arithmetic::mul_array_in_place, (a, b), ());
+raw_op!(mul_scalar, crate::arithmetic::mul_scalar_array, crate::arithmetic::mul_scalar_array_in_place, (a), (b: AF));
+raw_op!(abs_diff, crate::arithmetic::abs_diff_array, crate::arithmetic::abs_diff_array_in_place, (a, b), ());
+raw_op!(abs_diff_scalar, crate::arithmetic::abs_diff_scalar_array, crate::arithmetic::abs_diff_scalar_array_in_place, (a), (b: AF));
+raw_op!(min, crate::arithmetic::min_array, crate::arithmetic::min_array_in_place, (a, b), ());
+raw_op!(min_scalar, crate::arithmetic::min_scalar_array, crate::arithmetic::min_scalar_array_in_place, (a), (b: AF));
+raw_op!(max, crate::arithmetic::max_array, crate::arithmetic::max_array_in_place, (a, b), ());
+raw_op!(max_scalar, crate::arithmetic::max_scalar_array, crate::arithmetic::max_scalar_array_in_place, (a), (b: AF));
+raw_op!(neg, crate::arithmetic::neg_array, crate::arithmetic::neg_array_in_place, (input), ());
+raw_op!(sqr, crate::arithmetic::sqr_array, crate::arithmetic::sqr_array_in_place, (input), ());
+raw_op!(fma, crate::arithmetic::fma_array, crate::arithmetic::fma_array_in_place, (a, b, c), ());
+raw_op!(clamp, crate::arithmetic::clamp_array, crate::arithmetic::clamp_array_in_place, (input, lo, hi), ());
+raw_op!(lerp, crate::arithmetic::lerp_array, crate::arithmetic::lerp_array_in_place, (a, b, t), ());
+raw_op!(round_half_away, crate::arithmetic::round_half_away_array, crate::arithmetic::round_half_away_array_in_place, (input), ());
+raw_op!(round_half_even, crate::arithmetic::round_half_even_array, crate::arithmetic::round_half_even_array_in_place, (input), ());
+raw_op!(rint, crate::arithmetic::rint_array, crate::arithmetic::rint_array_in_place, (input), ());
+raw_op!(powi, crate::arithmetic::powi_array, crate::arithmetic::powi_array_in_place, (input), (n: i32));
+raw_op!(fma_scalar, crate::arithmetic::fma_scalar_array, crate::arithmetic::fma_scalar_array_in_place, (a), (b: AF, c: AF));
+raw_op!(clamp_scalar, crate::arithmetic::clamp_scalar_array, crate::arithmetic::clamp_scalar_array_in_place, (input), (lo: AF, hi: AF));
+raw_op!(lerp_scalar, crate::arithmetic::lerp_scalar_array, crate::arithmetic::lerp_scalar_array_in_place, (a, b), (t: AF));
+raw_op!(scalbn, crate::arithmetic::scalbn_array, crate::arithmetic::scalbn_array_in_place, (input), (n: i32));
+raw_op!(modf, crate::arithmetic::modf_array, (int_out: crate::arithmetic::modf_array_in_place_int, frac_out: crate::arithmetic::modf_array_in_place_frac), input);
+raw_op!(round_to_multiple, crate::arithmetic::round_to_multiple_array, crate::arithmetic::round_to_multiple_array_in_place, (input), (step: AF));
+raw_op!(round_to_decimals, crate::arithmetic::round_to_decimals_array, crate::arithmetic::round_to_decimals_array_in_place, (input), (decimals: i32));
+
+/// Raw-pointer form of [`crate::arithmetic::frexp_array`], over `len` elements.
+///
+/// `mantissa_out` may be equal to `input`, and the arrays must otherwise not overlap.
+///
+/// # Safety
+///
+/// Every pointer must be non-null, aligned and valid for `len` elements, and no reference to
+/// the memory behind the outputs may be live during the call.
+pub unsafe fn frexp<AF: AccelerateFloat>(
+    mantissa_out: *mut AF, exponent_out: *mut i32, input: *const AF, len: usize
+) -> Result<(), AccelerateError> {
+    if len == 0 {
+        return Ok(());
+    }
+    check_disjoint(mantissa_out, "mantissa_out", exponent_out, "exponent_out", len)?;
+    check_disjoint(exponent_out, "exponent_out", input, "input", len)?;
+    if mantissa_out.cast_const() == input {
+        unsafe {
+            crate::arithmetic::frexp_array_in_place(
+                slice::from_raw_parts_mut(exponent_out, len), slice::from_raw_parts_mut(mantissa_out, len)
+            )
+        }
+    } else {
+        check_disjoint(mantissa_out, "mantissa_out", input, "input", len)?;
+        unsafe {
+            crate::arithmetic::frexp_array(
+                slice::from_raw_parts_mut(mantissa_out, len),
+                slice::from_raw_parts_mut(exponent_out, len),
+                slice::from_raw_parts(input, len),
+            )
+        }
+    }
+}
+
+/// Raw-pointer form of [`crate::arithmetic::ldexp_array`], over `len` elements.
+///
+/// `out` may be equal to `input`, and must otherwise not overlap any input.
+///
+/// # Safety
+///
+/// Every pointer must be non-null, aligned and valid for `len` elements, and no reference to
+/// the memory behind `out` may be live during the call.
+pub unsafe fn ldexp<AF: AccelerateFloat>(
+    out: *mut AF, input: *const AF, exponents: *const i32, len: usize
+) -> Result<(), AccelerateError> {
+    if len == 0 {
+        return Ok(());
+    }
+    check_disjoint(out, "out", exponents, "exponents", len)?;
+    if out.cast_const() == input {
+        unsafe {
+            crate::arithmetic::ldexp_array_in_place(
+                slice::from_raw_parts_mut(out, len), slice::from_raw_parts(exponents, len)
+            )
+        }
+    } else {
+        check_disjoint(out, "out", input, "input", len)?;
+        unsafe {
+            crate::arithmetic::ldexp_array(
+                slice::from_raw_parts_mut(out, len),
+                slice::from_raw_parts(input, len),
+                slice::from_raw_parts(exponents, len),
+            )
+        }
+    }
+}
+
+/// Raw-pointer form of [`crate::arithmetic::ilogb_array`], over `len` elements.
+///
+/// `out` must not overlap `input`.
+///
+/// # Safety
+///
+/// Every pointer must be non-null, aligned and valid for `len` elements, and no reference to
+/// the memory behind `out` may be live during the call.
+pub unsafe fn ilogb<AF: AccelerateFloat>(
+    out: *mut i32, input: *const AF, len: usize
+) -> Result<(), AccelerateError> {
+    if len == 0 {
+        return Ok(());
+    }
+    check_disjoint(out, "out", input, "input", len)?;
+    unsafe { crate::arithmetic::ilogb_array(slice::from_raw_parts_mut(out, len), slice::from_raw_parts(input, len)) }
+}
+
+// ── exponential ──
+
+raw_op!(exp, crate::exponential::exp_array, crate::exponential::exp_array_in_place, (input), ());
+raw_op!(exp2, crate::exponential::exp2_array, crate::exponential::exp2_array_in_place, (input), ());
+raw_op!(expm1, crate::exponential::expm1_array, crate::exponential::expm1_array_in_place, (input), ());
+raw_op!(log, crate::exponential::log_array, crate::exponential::log_array_in_place, (input), ());
+raw_op!(log1p, crate::exponential::log1p_array, crate::exponential::log1p_array_in_place, (input), ());
+raw_op!(log2, crate::exponential::log2_array, crate::exponential::log2_array_in_place, (input), ());
+raw_op!(log10, crate::exponential::log10_array, crate::exponential::log10_array_in_place, (input), ());
+raw_op!(logb, crate::exponential::logb_array, crate::exponential::logb_array_in_place, (input), ());
+raw_op!(exp10, crate::exponential::exp10_array, crate::exponential::exp10_array_in_place, (input), ());
+raw_op!(exp2m1, crate::exponential::exp2m1_array, crate::exponential::exp2m1_array_in_place, (input), ());
+raw_op!(exp10m1, crate::exponential::exp10m1_array, crate::exponential::exp10m1_array_in_place, (input), ());
+raw_op!(logn, crate::exponential::logn_array, crate::exponential::logn_array_in_place, (input), (base: AF));
+raw_op!(softmax, crate::exponential::softmax_array, crate::exponential::softmax_array_in_place, (input), ());
+raw_op!(log_softmax, crate::exponential::log_softmax_array, crate::exponential::log_softmax_array_in_place, (input), ());
+
+// ── trig ──
+
+raw_op!(sin, crate::trig::sin_array, crate::trig::sin_array_in_place, (input), ());
+raw_op!(sinpi, crate::trig::sinpi_array, crate::trig::sinpi_array_in_place, (input), ());
+raw_op!(cos, crate::trig::cos_array, crate::trig::cos_array_in_place, (input), ());
+raw_op!(cospi, crate::trig::cospi_array, crate::trig::cospi_array_in_place, (input), ());
+raw_op!(tan, crate::trig::tan_array, crate::trig::tan_array_in_place, (input), ());
+raw_op!(tanpi, crate::trig::tanpi_array, crate::trig::tanpi_array_in_place, (input), ());
+raw_op!(asin, crate::trig::asin_array, crate::trig::asin_array_in_place, (input), ());
+raw_op!(acos, crate::trig::acos_array, crate::trig::acos_array_in_place, (input), ());
+raw_op!(atan, crate::trig::atan_array, crate::trig::atan_array_in_place, (input), ());
+raw_op!(atan2, crate::trig::atan2_array, crate::trig::atan2_array_in_place,
+    rhs: crate::trig::atan2_array_in_place_rhs, (y, x), ());
+raw_op!(sincos, crate::trig::sincos_array, (sin_out: crate::trig::sincos_array_in_place_sin, cos_out: crate::trig::sincos_array_in_place_cos), input);
+raw_op!(sincospi, crate::trig::sincospi_array, (sin_out: crate::trig::sincospi_array_in_place_sin, cos_out: crate::trig::sincospi_array_in_place_cos), input);
+raw_op!(sind, crate::trig::sind_array, crate::trig::sind_array_in_place, (input), ());
+raw_op!(cosd, crate::trig::cosd_array, crate::trig::cosd_array_in_place, (input), ());
+raw_op!(tand, crate::trig::tand_array, crate::trig::tand_array_in_place, (input), ());
+raw_op!(sincosd, crate::trig::sincosd_array, (sin_out: crate::trig::sincosd_array_in_place_sin, cos_out: crate::trig::sincosd_array_in_place_cos), input);
+raw_op!(asind, crate::trig::asind_array, crate::trig::asind_array_in_place, (input), ());
+raw_op!(acosd, crate::trig::acosd_array, crate::trig::acosd_array_in_place, (input), ());
+raw_op!(atand, crate::trig::atand_array, crate::trig::atand_array_in_place, (input), ());
+raw_op!(atan2d, crate::trig::atan2d_array, crate::trig::atan2d_array_in_place, (y, x), ());
+
+/// Raw-pointer form of [`crate::trig::cosisin_array`], over `len` elements.
+///
+/// `out` must not overlap `input`.
+///
+/// # Safety
+///
+/// Every pointer must be non-null, aligned and valid for `len` elements, and no reference to
+/// the memory behind `out` may be live during the call.
+pub unsafe fn cosisin<AF: AccelerateFloat>(
+    out: *mut AccelerateComplex<AF>, input: *const AF, len: usize
+) -> Result<(), AccelerateError> {
+    if len == 0 {
+        return Ok(());
+    }
+    check_disjoint(out, "out", input, "input", len)?;
+    unsafe { crate::trig::cosisin_array(slice::from_raw_parts_mut(out, len), slice::from_raw_parts(input, len)) }
+}
+
+// ── hyperbolic ──
+
+raw_op!(sinh, crate::hyperbolic::sinh_array, crate::hyperbolic::sinh_array_in_place, (input), ());
+raw_op!(cosh, crate::hyperbolic::cosh_array, crate::hyperbolic::cosh_array_in_place, (input), ());
+raw_op!(tanh, crate::hyperbolic::tanh_array, crate::hyperbolic::tanh_array_in_place, (input), ());
+raw_op!(asinh, crate::hyperbolic::asinh_array, crate::hyperbolic::asinh_array_in_place, (input), ());
+raw_op!(acosh, crate::hyperbolic::acosh_array, crate::hyperbolic::acosh_array_in_place, (input), ());
+raw_op!(atanh, crate::hyperbolic::atanh_array, crate::hyperbolic::atanh_array_in_place, (input), ());
+
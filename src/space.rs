@@ -0,0 +1,207 @@
+//! Evenly spaced and geometrically spaced grids, written into slices or, with the `alloc`
+//! feature, collected into vectors
+//!
+//! Each element is computed from its index rather than by repeatedly adding a step, so rounding
+//! errors do not accumulate along the grid, and the endpoints of [`linspace_fill`] and
+//! [`geomspace_fill`] are exact. The functions named after an operation, such as
+//! [`sin_linspace`], apply it to an evenly spaced grid a tile at a time without writing the grid
+//! out in full.
+//!
+//! ```rust
+//! use vforce::space::{linspace_fill, sin_linspace};
+//!
+//! let mut grid = [0.0f64; 5];
+//! linspace_fill(&mut grid, 0.0, 1.0);
+//! assert_eq!(grid, [0.0, 0.25, 0.5, 0.75, 1.0]);
+//!
+//! let mut out = [0.0f64; 5];
+//! sin_linspace(&mut out, 0.0, core::f64::consts::PI);
+//! assert!((out[2] - 1.0).abs() < 1e-15);
+//! ```
+use super::*;
+use crate::arithmetic::pow_array;
+use crate::op::Op;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Returns the step between elements of a grid of `len` elements from `start` to `stop`.
+fn linspace_step<AF: AccelerateFloat>(len: usize, start: AF, stop: AF) -> AF {
+    (stop - start) / AF::from_f64(len.saturating_sub(1).max(1) as f64)
+}
+
+/// Writes the elements of `out` at indices `first..first + out.len()` of the grid of `len`
+/// elements from `start` to `stop`.
+fn linspace_part<AF: AccelerateFloat>(out: &mut [AF], first: usize, len: usize, start: AF, stop: AF) {
+    let step = linspace_step(len, start, stop);
+    for (i, o) in (first..).zip(out.iter_mut()) {
+        *o = if i + 1 == len && len > 1 { stop } else { AF::from_f64(i as f64).mul_add(step, start) };
+    }
+}
+
+/// Fills `out` with evenly spaced values from `start` to `stop`, both included.
+///
+/// A single element is set to `start`.
+pub fn linspace_fill<AF: AccelerateFloat>(out: &mut [AF], start: AF, stop: AF) {
+    linspace_part(out, 0, out.len(), start, stop);
+}
+
+/// Fills `out` with the values `start`, `start + step`, `start + 2 * step`, and so on.
+pub fn arange_fill<AF: AccelerateFloat>(out: &mut [AF], start: AF, step: AF) {
+    for (i, o) in out.iter_mut().enumerate() {
+        *o = AF::from_f64(i as f64).mul_add(step, start);
+    }
+}
+
+/// Fills `out` with `base` raised to evenly spaced exponents from `start` to `stop`, both
+/// included.
+pub fn logspace_fill<AF: AccelerateFloat>(out: &mut [AF], start: AF, stop: AF, base: AF) {
+    let len = out.len();
+    let bases = [base; TILE];
+    let mut exponents = [AF::ZERO; TILE];
+    for (i, tile) in out.chunks_mut(TILE).enumerate() {
+        let exponents = &mut exponents[..tile.len()];
+        linspace_part(exponents, i * TILE, len, start, stop);
+        infallible(pow_array(tile, &bases[..tile.len()], exponents));
+    }
+}
+
+/// Fills `out` with values from `start` to `stop`, both included and exact, in which each is a
+/// constant multiple of the one before.
+///
+/// Returns `AccelerateError::DomainError` if `start` is zero, or if `stop` is zero or has the
+/// opposite sign to `start`, for which no such sequence exists.
+pub fn geomspace_fill<AF: AccelerateFloat>(out: &mut [AF], start: AF, stop: AF) -> Result<(), AccelerateError> {
+    if start == AF::ZERO {
+        return Err(AccelerateError::DomainError { index: 0, value: start.to_f64() });
+    }
+    let ratio = stop / start;
+    if ratio.is_nan() || ratio <= AF::ZERO {
+        return Err(AccelerateError::DomainError { index: out.len().saturating_sub(1), value: stop.to_f64() });
+    }
+    // start * ratio^t for t evenly spaced from 0 to 1
+    logspace_fill(out, AF::ZERO, AF::ONE, ratio);
+    for o in out.iter_mut() {
+        *o = *o * start;
+    }
+    if let Some(first) = out.first_mut() {
+        *first = start;
+    }
+    if let [_, .., last] = out {
+        *last = stop;
+    }
+    Ok(())
+}
+
+/// Returns `len` evenly spaced values from `start` to `stop`, both included.
+#[cfg(feature = "alloc")]
+pub fn linspace<AF: AccelerateFloat>(start: AF, stop: AF, len: usize) -> Vec<AF> {
+    let mut out = alloc::vec![AF::ZERO; len];
+    linspace_fill(&mut out, start, stop);
+    out
+}
+
+/// Returns the values `start`, `start + step`, `start + 2 * step`, and so on, up to but
+/// excluding `stop`.
+///
+/// The result is empty if `step` is zero or NaN, or does not lead from `start` towards `stop`,
+/// or if either end is not finite.
+#[cfg(feature = "alloc")]
+pub fn arange<AF: AccelerateFloat>(start: AF, stop: AF, step: AF) -> Vec<AF> {
+    let count = AccelerateFloat::ceil(((stop - start) / step).to_f64());
+    let len = if count.is_finite() && count > 0.0 { count as usize } else { 0 };
+    let mut out = alloc::vec![AF::ZERO; len];
+    arange_fill(&mut out, start, step);
+    out
+}
+
+/// Returns `len` values of `base` raised to evenly spaced exponents from `start` to `stop`, both
+/// included.
+#[cfg(feature = "alloc")]
+pub fn logspace<AF: AccelerateFloat>(start: AF, stop: AF, len: usize, base: AF) -> Vec<AF> {
+    let mut out = alloc::vec![AF::ZERO; len];
+    logspace_fill(&mut out, start, stop, base);
+    out
+}
+
+/// Returns `len` values from `start` to `stop`, both included, in which each is a constant
+/// multiple of the one before.
+///
+/// Returns `AccelerateError::DomainError` under the same conditions as [`geomspace_fill`].
+#[cfg(feature = "alloc")]
+pub fn geomspace<AF: AccelerateFloat>(start: AF, stop: AF, len: usize) -> Result<Vec<AF>, AccelerateError> {
+    let mut out = alloc::vec![AF::ZERO; len];
+    geomspace_fill(&mut out, start, stop)?;
+    Ok(out)
+}
+
+/// Applies the unary elementwise `op` to the evenly spaced values from `start` to `stop`, writing
+/// the results to `out`.
+fn apply_linspace<AF: AccelerateFloat>(op: Op, out: &mut [AF], start: AF, stop: AF) {
+    let len = out.len();
+    let mut grid = [AF::ZERO; TILE];
+    for (i, tile) in out.chunks_mut(TILE).enumerate() {
+        let grid = &mut grid[..tile.len()];
+        linspace_part(grid, i * TILE, len, start, stop);
+        infallible(op.apply(tile, &[grid]));
+    }
+}
+
+macro_rules! linspace_fns {
+    ($($name:ident => $op:ident;)*) => {
+        $(
+            #[doc = concat!("Applies [`Op::", stringify!($op), "`] to evenly spaced values from `start` to `stop`, both included, writing the results to `out`.")]
+            pub fn $name<AF: AccelerateFloat>(out: &mut [AF], start: AF, stop: AF) {
+                apply_linspace(Op::$op, out, start, stop);
+            }
+        )*
+    };
+}
+
+linspace_fns! {
+    cbrt_linspace => Cbrt;
+    ceil_linspace => Ceil;
+    floor_linspace => Floor;
+    fabs_linspace => Fabs;
+    int_linspace => Int;
+    nint_linspace => Nint;
+    rsqrt_linspace => Rsqrt;
+    sqrt_linspace => Sqrt;
+    rec_linspace => Rec;
+    neg_linspace => Neg;
+    sqr_linspace => Sqr;
+    round_half_away_linspace => RoundHalfAway;
+    round_half_even_linspace => RoundHalfEven;
+    rint_linspace => Rint;
+    exp_linspace => Exp;
+    exp2_linspace => Exp2;
+    expm1_linspace => Expm1;
+    log_linspace => Log;
+    log1p_linspace => Log1p;
+    log2_linspace => Log2;
+    log10_linspace => Log10;
+    logb_linspace => Logb;
+    exp10_linspace => Exp10;
+    exp2m1_linspace => Exp2m1;
+    exp10m1_linspace => Exp10m1;
+    sin_linspace => Sin;
+    sinpi_linspace => Sinpi;
+    cos_linspace => Cos;
+    cospi_linspace => Cospi;
+    tan_linspace => Tan;
+    tanpi_linspace => Tanpi;
+    asin_linspace => Asin;
+    acos_linspace => Acos;
+    atan_linspace => Atan;
+    sind_linspace => Sind;
+    cosd_linspace => Cosd;
+    tand_linspace => Tand;
+    asind_linspace => Asind;
+    acosd_linspace => Acosd;
+    atand_linspace => Atand;
+    sinh_linspace => Sinh;
+    cosh_linspace => Cosh;
+    tanh_linspace => Tanh;
+    asinh_linspace => Asinh;
+    acosh_linspace => Acosh;
+    atanh_linspace => Atanh;
+}
@@ -0,0 +1,172 @@
+//! Iterator adapters applying the unary array functions to streams of elements
+//!
+//! Elements are gathered from the source iterator into a tile on the stack, whose size is a const
+//! generic parameter defaulting to 256 elements, so that each call to the vectorized function
+//! processes a full tile. Results are yielded in order as the tile is drained.
+//!
+//! ```rust
+//! use vforce::op::Op;
+//! use vforce::stream::VfMapExt;
+//!
+//! let roots: Vec<f64> = (1..=4).map(|x| x as f64).vf_map(Op::Sqrt).collect();
+//! assert_eq!(roots[3], 2.0);
+//!
+//! // or with a tile of 64 elements
+//! let exps: Vec<f64> = roots.iter().copied().vf_map_tiled::<64>(Op::Exp).collect();
+//! assert_eq!(exps[3], 2.0f64.exp());
+//!
+//! // or through the function named after the operation
+//! let logs: Vec<f64> = vforce::stream::log(exps).collect();
+//! assert_eq!(logs[3], 2.0);
+//! ```
+use super::*;
+use crate::op::Op;
+
+/// Iterator applying a unary elementwise [`Op`] to the elements of `I`, `N` at a time
+///
+/// Created by [`VfMapExt::vf_map`] or the functions of this module.
+pub struct VfMap<I, AF, const N: usize = TILE> {
+    iter: I,
+    op: Op,
+    input: [AF; N],
+    out: [AF; N],
+    pos: usize,
+    len: usize,
+}
+
+impl<I: Iterator<Item = AF>, AF: AccelerateFloat, const N: usize> VfMap<I, AF, N> {
+    /// Creates an adapter applying `op` to the elements of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `op` is not unary and elementwise, or if `N` is zero.
+    pub fn new(iter: I, op: Op) -> Self {
+        assert!(op.arity() == 1 && op.is_elementwise(), "{} cannot be applied to a stream of elements", op);
+        assert!(N > 0, "the tile size must be at least one element");
+        Self { iter, op, input: [AF::ZERO; N], out: [AF::ZERO; N], pos: 0, len: 0 }
+    }
+
+    /// Returns the operation being applied.
+    pub fn op(&self) -> Op {
+        self.op
+    }
+
+    /// Consumes the adapter, returning the source iterator. Elements already taken from it into
+    /// the current tile are lost.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator<Item = AF>, AF: AccelerateFloat, const N: usize> Iterator for VfMap<I, AF, N> {
+    type Item = AF;
+
+    fn next(&mut self) -> Option<AF> {
+        if self.pos == self.len {
+            self.len = 0;
+            for (slot, x) in self.input.iter_mut().zip(&mut self.iter) {
+                *slot = x;
+                self.len += 1;
+            }
+            if self.len == 0 {
+                return None;
+            }
+            self.pos = 0;
+            self.op.apply(&mut self.out[..self.len], &[&self.input[..self.len]])
+                .expect("unary operations only fail on mismatched lengths");
+        }
+        self.pos += 1;
+        Some(self.out[self.pos - 1])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.len - self.pos;
+        let (lower, upper) = self.iter.size_hint();
+        (lower.saturating_add(buffered), upper.and_then(|upper| upper.checked_add(buffered)))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = AF>, AF: AccelerateFloat, const N: usize> ExactSizeIterator for VfMap<I, AF, N> {}
+
+/// Adds [`vf_map`](VfMapExt::vf_map) to iterators of `f32` or `f64`
+pub trait VfMapExt<AF: AccelerateFloat>: Iterator<Item = AF> + Sized {
+    /// Applies the unary elementwise `op` to each element, in tiles of 256 elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `op` is not unary and elementwise; see [`Op::is_elementwise`].
+    fn vf_map(self, op: Op) -> VfMap<Self, AF> {
+        VfMap::new(self, op)
+    }
+
+    /// Applies the unary elementwise `op` to each element, in tiles of `N` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `op` is not unary and elementwise, or if `N` is zero.
+    fn vf_map_tiled<const N: usize>(self, op: Op) -> VfMap<Self, AF, N> {
+        VfMap::new(self, op)
+    }
+}
+
+impl<I: Iterator<Item = AF>, AF: AccelerateFloat> VfMapExt<AF> for I {}
+
+macro_rules! stream_fns {
+    ($($name:ident => $op:ident;)*) => {
+        $(
+            #[doc = concat!("Applies [`Op::", stringify!($op), "`] to the elements of `iter`, in tiles of 256 elements.")]
+            pub fn $name<I: IntoIterator<Item = AF>, AF: AccelerateFloat>(iter: I) -> VfMap<I::IntoIter, AF> {
+                VfMap::new(iter.into_iter(), Op::$op)
+            }
+        )*
+    };
+}
+
+stream_fns! {
+    cbrt => Cbrt;
+    ceil => Ceil;
+    floor => Floor;
+    fabs => Fabs;
+    int => Int;
+    nint => Nint;
+    rsqrt => Rsqrt;
+    sqrt => Sqrt;
+    rec => Rec;
+    neg => Neg;
+    sqr => Sqr;
+    round_half_away => RoundHalfAway;
+    round_half_even => RoundHalfEven;
+    rint => Rint;
+    exp => Exp;
+    exp2 => Exp2;
+    expm1 => Expm1;
+    log => Log;
+    log1p => Log1p;
+    log2 => Log2;
+    log10 => Log10;
+    logb => Logb;
+    exp10 => Exp10;
+    exp2m1 => Exp2m1;
+    exp10m1 => Exp10m1;
+    sin => Sin;
+    sinpi => Sinpi;
+    cos => Cos;
+    cospi => Cospi;
+    tan => Tan;
+    tanpi => Tanpi;
+    asin => Asin;
+    acos => Acos;
+    atan => Atan;
+    sind => Sind;
+    cosd => Cosd;
+    tand => Tand;
+    asind => Asind;
+    acosd => Acosd;
+    atand => Atand;
+    sinh => Sinh;
+    cosh => Cosh;
+    tanh => Tanh;
+    asinh => Asinh;
+    acosh => Acosh;
+    atanh => Atanh;
+}
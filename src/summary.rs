@@ -0,0 +1,173 @@
+//! Groups together functions which report NaN, infinite, subnormal and zero results, in the
+//! manner of the IEEE 754 exception flags
+//!
+//! VForce does not expose its floating-point status, so the wrappers here run an elementwise
+//! function in tiles small enough to stay in cache and classify each tile of results right after
+//! it is computed, rather than in a second pass over the whole output.
+//!
+//! ```rust
+//! use vforce::exponential::exp_array;
+//! use vforce::summary::summarize_unary;
+//! let input: Vec<f64> = vec![1.0, 800.0, -800.0, f64::NAN];
+//! let mut out = vec![0.0f64; 4];
+//!
+//! let summary = summarize_unary(exp_array, &mut out, &input).unwrap();
+//! assert_eq!(summary.infinite.first, Some(1));
+//! assert_eq!(summary.zero.first, Some(2));
+//! assert_eq!(summary.nan.count, 1);
+//! assert!(!summary.is_finite());
+//! ```
+use super::*;
+
+/// The number of results in one class, and the index of the first of them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClassSummary {
+    /// Number of results in the class
+    pub count: usize,
+    /// Index of the first result in the class, if there is one
+    pub first: Option<usize>,
+}
+
+impl ClassSummary {
+    fn record(&mut self, index: usize) {
+        self.count += 1;
+        self.first.get_or_insert(index);
+    }
+}
+
+/// Counts of the exceptional results of a computation. NaN results correspond to the IEEE invalid
+/// flag, infinite results to overflow or division by zero, and subnormal results to underflow.
+/// `zero` counts every zero result; only some of them come from underflow, since exact zeros such
+/// as `sin(0)` or `x - x` raise no flag.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputSummary {
+    /// NaN results
+    pub nan: ClassSummary,
+    /// Positive or negative infinite results
+    pub infinite: ClassSummary,
+    /// Subnormal results
+    pub subnormal: ClassSummary,
+    /// Positive or negative zero results, whether exact or underflowed
+    pub zero: ClassSummary,
+}
+
+impl OutputSummary {
+    /// Classifies every element of `values`.
+    pub fn of<AF: AccelerateFloat>(values: &[AF]) -> Self {
+        let mut summary = Self::default();
+        summary.accumulate(0, values);
+        summary
+    }
+
+    /// Classifies `values`, which start at index `offset` of the full output.
+    fn accumulate<AF: AccelerateFloat>(&mut self, offset: usize, values: &[AF]) {
+        for (i, &x) in values.iter().enumerate() {
+            if x.is_nan() {
+                self.nan.record(offset + i);
+            } else if !x.is_finite() {
+                self.infinite.record(offset + i);
+            } else if x.is_subnormal() {
+                self.subnormal.record(offset + i);
+            } else if x == AF::ZERO {
+                self.zero.record(offset + i);
+            }
+        }
+    }
+
+    /// Returns true if there were no NaN or infinite results.
+    pub fn is_finite(&self) -> bool {
+        self.nan.count == 0 && self.infinite.count == 0
+    }
+
+    /// Returns the index of the first NaN or infinite result, if there is one.
+    pub fn first_non_finite(&self) -> Option<usize> {
+        match (self.nan.first, self.infinite.first) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// Returns `AccelerateError::NonFinite` for the first NaN or infinite element of `values`.
+pub fn check_finite_array<AF: AccelerateFloat>(values: &[AF]) -> Result<(), AccelerateError> {
+    match values.iter().position(|x| !x.is_finite()) {
+        Some(index) => Err(AccelerateError::NonFinite { index, value: values[index].to_f64() }),
+        None => Ok(()),
+    }
+}
+
+/// Applies the elementwise function `f`, such as [`exp_array`](crate::exponential::exp_array),
+/// to `input`, writing the results to `out` and returning a summary of them.
+///
+/// `f` is called once per tile of the arrays, so it must compute each result from the
+/// corresponding input alone; functions over whole arrays or rows, such as softmax, give wrong
+/// results here.
+pub fn summarize_unary<AF: AccelerateFloat>(
+    mut f: impl FnMut(&mut [AF], &[AF]) -> Result<(), AccelerateError>,
+    out: &mut [AF], input: &[AF]
+) -> Result<OutputSummary, AccelerateError> {
+    check_lengths!(input, out)?;
+    let mut summary = OutputSummary::default();
+    for (t, (out_tile, in_tile)) in out.chunks_mut(TILE).zip(input.chunks(TILE)).enumerate() {
+        f(out_tile, in_tile)?;
+        summary.accumulate(t * TILE, out_tile);
+    }
+    Ok(summary)
+}
+
+/// Applies the elementwise function `f`, such as
+/// [`exp_array_in_place`](crate::exponential::exp_array_in_place), to `input`, overwriting it with
+/// the results and returning a summary of them.
+///
+/// `f` is called once per tile of the array, so it must compute each result from the
+/// corresponding input alone.
+pub fn summarize_unary_in_place<AF: AccelerateFloat>(
+    mut f: impl FnMut(&mut [AF]), input: &mut [AF]
+) -> OutputSummary {
+    let mut summary = OutputSummary::default();
+    for (t, tile) in input.chunks_mut(TILE).enumerate() {
+        f(tile);
+        summary.accumulate(t * TILE, tile);
+    }
+    summary
+}
+
+/// Applies the elementwise function `f`, such as [`pow_array`](crate::arithmetic::pow_array),
+/// to `a` and `b`, writing the results to `out` and returning a summary of them.
+///
+/// `f` is called once per tile of the arrays, so it must compute each result from the
+/// corresponding inputs alone.
+pub fn summarize_binary<AF: AccelerateFloat>(
+    mut f: impl FnMut(&mut [AF], &[AF], &[AF]) -> Result<(), AccelerateError>,
+    out: &mut [AF], a: &[AF], b: &[AF]
+) -> Result<OutputSummary, AccelerateError> {
+    check_lengths!(a, b, out)?;
+    let mut summary = OutputSummary::default();
+    for (t, (out_tile, (a_tile, b_tile))) in out.chunks_mut(TILE)
+        .zip(a.chunks(TILE).zip(b.chunks(TILE)))
+        .enumerate()
+    {
+        f(out_tile, a_tile, b_tile)?;
+        summary.accumulate(t * TILE, out_tile);
+    }
+    Ok(summary)
+}
+
+/// Applies the elementwise function `f`, such as
+/// [`pow_array_in_place`](crate::arithmetic::pow_array_in_place), to `a` and `b`, overwriting `a`
+/// with the results and returning a summary of them.
+///
+/// `f` is called once per tile of the arrays, so it must compute each result from the
+/// corresponding inputs alone.
+pub fn summarize_binary_in_place<AF: AccelerateFloat>(
+    mut f: impl FnMut(&mut [AF], &[AF]) -> Result<(), AccelerateError>,
+    a: &mut [AF], b: &[AF]
+) -> Result<OutputSummary, AccelerateError> {
+    check_lengths!(a, b)?;
+    let mut summary = OutputSummary::default();
+    for (t, (a_tile, b_tile)) in a.chunks_mut(TILE).zip(b.chunks(TILE)).enumerate() {
+        f(a_tile, b_tile)?;
+        summary.accumulate(t * TILE, a_tile);
+    }
+    Ok(summary)
+}
@@ -13,6 +13,12 @@ unary_vforce_op!(
     /// If x is +/-0, the result preserves the signed zero.
     /// If x is +/-inf, the result is NaN.
     sin_array_in_place,
+    /// Computes the sine of each element (in radians), writing the results to `out` where `mask`
+    /// is set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If x is +/-inf, the result is NaN.
+    sin_array_masked,
     accelerate_sin, input);
 
 unary_vforce_op!(
@@ -20,6 +26,9 @@ unary_vforce_op!(
     sinpi_array,
     /// Computes sin(x * pi) for each element, overwriting `input` with the results.
     sinpi_array_in_place,
+    /// Computes sin(x * pi) for each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    sinpi_array_masked,
     accelerate_sinpi, input);
 
 unary_vforce_op!(
@@ -31,6 +40,11 @@ unary_vforce_op!(
     ///
     /// If x is +/-inf, the result is NaN.
     cos_array_in_place,
+    /// Computes the cosine of each element (in radians), writing the results to `out` where
+    /// `mask` is set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-inf, the result is NaN.
+    cos_array_masked,
     accelerate_cos, input);
 
 unary_vforce_op!(
@@ -38,6 +52,9 @@ unary_vforce_op!(
     cospi_array,
     /// Computes cos(x * pi) for each element, overwriting `input` with the results.
     cospi_array_in_place,
+    /// Computes cos(x * pi) for each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    cospi_array_masked,
     accelerate_cospi, input);
 
 unary_vforce_op!(
@@ -51,6 +68,12 @@ unary_vforce_op!(
     /// If x is +/-0, the result preserves the signed zero.
     /// If x is +/-inf, the result is NaN.
     tan_array_in_place,
+    /// Computes the tangent of each element (in radians), writing the results to `out` where
+    /// `mask` is set and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If x is +/-inf, the result is NaN.
+    tan_array_masked,
     accelerate_tan, input);
 
 unary_vforce_op!(
@@ -58,6 +81,9 @@ unary_vforce_op!(
     tanpi_array,
     /// Computes tan(x * pi) for each element, overwriting `input` with the results.
     tanpi_array_in_place,
+    /// Computes tan(x * pi) for each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    tanpi_array_masked,
     accelerate_tanpi, input);
 
 unary_vforce_op!(
@@ -71,8 +97,23 @@ unary_vforce_op!(
     /// If x is +/-0, the result preserves the signed zero.
     /// If |x| > 1, the result is NaN.
     asin_array_in_place,
+    /// Computes the arcsine of each element, writing the results to `out` where `mask` is set and
+    /// leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If |x| > 1, the result is NaN.
+    asin_array_masked,
     accelerate_asin, input);
 
+checked_unary_op!(
+    /// Computes the arcsine of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with |x| > 1 without writing anything.
+    asin_array_checked,
+    /// Computes the arcsine of each element, overwriting `input` with the results, or returns
+    /// `AccelerateError::DomainError` for the first element with |x| > 1 without modifying `input`.
+    asin_array_in_place_checked,
+    asin_array, asin_array_in_place, crate::domain::Domain::UnitInterval);
+
 unary_vforce_op!(
     /// Computes the arccosine of each element, writing the results to `out`.
     ///
@@ -84,8 +125,23 @@ unary_vforce_op!(
     /// If x is 1, the result is +0.
     /// If |x| > 1, the result is NaN.
     acos_array_in_place,
+    /// Computes the arccosine of each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is 1, the result is +0.
+    /// If |x| > 1, the result is NaN.
+    acos_array_masked,
     accelerate_acos, input);
 
+checked_unary_op!(
+    /// Computes the arccosine of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with |x| > 1 without writing anything.
+    acos_array_checked,
+    /// Computes the arccosine of each element, overwriting `input` with the results, or returns
+    /// `AccelerateError::DomainError` for the first element with |x| > 1 without modifying `input`.
+    acos_array_in_place_checked,
+    acos_array, acos_array_in_place, crate::domain::Domain::UnitInterval);
+
 unary_vforce_op!(
     /// Computes the arctangent of each element, writing the results to `out`.
     ///
@@ -97,6 +153,12 @@ unary_vforce_op!(
     /// If x is +/-0, the result preserves the signed zero.
     /// If x is +/-inf, the result is +/-pi/2.
     atan_array_in_place,
+    /// Computes the arctangent of each element, writing the results to `out` where `mask` is set
+    /// and leaving the other elements of `out` unchanged.
+    ///
+    /// If x is +/-0, the result preserves the signed zero.
+    /// If x is +/-inf, the result is +/-pi/2.
+    atan_array_masked,
     accelerate_atan, input);
 
 binary_vforce_op!(
@@ -158,8 +220,53 @@ binary_vforce_op!(
     ///
     /// +/-inf | +inf |   +/-pi/4
     atan2_array_in_place,
+    /// Computes the two-argument arctangent atan2(y, x), overwriting `x` with the results.
+    ///
+    /// The signs of both arguments are used to determine the quadrant of the calculated value.
+    /// The following special values of x and y produce the given value of z:
+    ///
+    /// y      |   x  |   result
+    ///
+    /// +/-0   |  -0  |   +/-pi
+    ///
+    /// +/-0   |  +0  |   +/-0
+    ///
+    /// +/-0   |  <0  |   +/-pi
+    ///
+    /// +/-0   |  >0  |   +/-0
+    ///
+    ///  \>0    | +/-0 |   +pi/2
+    ///
+    ///  <0    | +/-0 |   -pi/2
+    ///
+    /// +/-y   | -inf |   +/-pi
+    ///
+    /// +/-y   | +inf |   +/-0
+    ///
+    /// +/-inf |   x  |   +/-pi/2
+    ///
+    /// +/-inf | -inf |   +/-3pi/4
+    ///
+    /// +/-inf | +inf |   +/-pi/4
+    atan2_array_in_place_rhs,
     accelerate_atan2, y, x);
 
+checked_binary_op!(
+    /// Computes the two-argument arctangent atan2(y, x), writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first pair with y and x both zero, where the angle
+    /// is undefined, without writing anything.
+    atan2_array_checked,
+    /// Computes the two-argument arctangent atan2(y, x), overwriting `y` with the results, or
+    /// returns `AccelerateError::DomainError` for the first pair with y and x both zero without
+    /// modifying `y`.
+    atan2_array_in_place_checked,
+    /// Computes the two-argument arctangent atan2(y, x), overwriting `x` with the results, or
+    /// returns `AccelerateError::DomainError` for the first pair with y and x both zero without
+    /// modifying `x`.
+    atan2_array_in_place_rhs_checked,
+    atan2_array, atan2_array_in_place, atan2_array_in_place_rhs, y, x,
+    crate::domain::BinaryDomain::NotBothZero);
+
 // ── Special: sincos and cosisin ──
 
 /// Computes the sine and cosine of each element simultaneously, writing the results
@@ -167,7 +274,7 @@ binary_vforce_op!(
 pub fn sincos_array<AF: AccelerateFloat>(
     sin_out: &mut [AF], cos_out: &mut [AF], input: &[AF]
 ) -> Result<(), AccelerateError> {
-    check_lengths_2(input.len(), sin_out.len(), cos_out.len())?;
+    check_lengths!(input, sin_out, cos_out)?;
     for ((sin_chunk, cos_chunk), in_chunk) in sin_out.chunks_mut(CHUNK)
         .zip(cos_out.chunks_mut(CHUNK))
         .zip(input.chunks(CHUNK))
@@ -182,7 +289,7 @@ pub fn sincos_array<AF: AccelerateFloat>(
 pub fn sincos_array_in_place_sin<AF: AccelerateFloat>(
     cos_out: &mut [AF], input: &mut [AF]
 ) -> Result<(), AccelerateError> {
-    check_lengths_1(input.len(), cos_out.len())?;
+    check_lengths!(input, cos_out)?;
     for (in_chunk, cos_chunk) in input.chunks_mut(CHUNK).zip(cos_out.chunks_mut(CHUNK)) {
         let count = in_chunk.len() as i32;
         unsafe { AF::accelerate_sincos(in_chunk.as_mut_ptr(), cos_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count); }
@@ -195,7 +302,7 @@ pub fn sincos_array_in_place_sin<AF: AccelerateFloat>(
 pub fn sincos_array_in_place_cos<AF: AccelerateFloat>(
     sin_out: &mut [AF], input: &mut [AF]
 ) -> Result<(), AccelerateError> {
-    check_lengths_1(input.len(), sin_out.len())?;
+    check_lengths!(input, sin_out)?;
     for (in_chunk, sin_chunk) in input.chunks_mut(CHUNK).zip(sin_out.chunks_mut(CHUNK)) {
         let count = in_chunk.len() as i32;
         unsafe { AF::accelerate_sincos(sin_chunk.as_mut_ptr(), in_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count); }
@@ -209,10 +316,412 @@ pub fn sincos_array_in_place_cos<AF: AccelerateFloat>(
 pub fn cosisin_array<AF: AccelerateFloat>(
     out: &mut [AccelerateComplex<AF>], input: &[AF]
 ) -> Result<(), AccelerateError> {
-    check_lengths_1(out.len(), input.len())?;
+    check_lengths!(input, out)?;
     for (out_chunk, in_chunk) in out.chunks_mut(CHUNK).zip(input.chunks(CHUNK)) {
         let count = in_chunk.len() as i32;
         unsafe { AF::accelerate_cosisin(out_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count); }
     }
     Ok(())
 }
+
+// ── Special: sincospi ──
+
+/// Computes sin(x * pi) and cos(x * pi) of each element, writing the results to `sin_out` and
+/// `cos_out` respectively.
+///
+/// VForce has no combined kernel for these, so the `sinpi` and `cospi` kernels are called in turn.
+pub fn sincospi_array<AF: AccelerateFloat>(
+    sin_out: &mut [AF], cos_out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, sin_out, cos_out)?;
+    for ((sin_chunk, cos_chunk), in_chunk) in sin_out.chunks_mut(CHUNK)
+        .zip(cos_out.chunks_mut(CHUNK))
+        .zip(input.chunks(CHUNK))
+    {
+        let count = in_chunk.len() as i32;
+        unsafe {
+            AF::accelerate_sinpi(sin_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count);
+            AF::accelerate_cospi(cos_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count);
+        }
+    }
+    Ok(())
+}
+
+/// Computes sin(x * pi) and cos(x * pi) of each element, writing the `sinpi` results into
+/// `input` and the `cospi` results into `cos_out`
+pub fn sincospi_array_in_place_sin<AF: AccelerateFloat>(
+    cos_out: &mut [AF], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, cos_out)?;
+    for (in_chunk, cos_chunk) in input.chunks_mut(CHUNK).zip(cos_out.chunks_mut(CHUNK)) {
+        let count = in_chunk.len() as i32;
+        unsafe {
+            AF::accelerate_cospi(cos_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count);
+            AF::accelerate_sinpi(in_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count);
+        }
+    }
+    Ok(())
+}
+
+/// Computes sin(x * pi) and cos(x * pi) of each element, writing the `cospi` results into
+/// `input` and the `sinpi` results into `sin_out`
+pub fn sincospi_array_in_place_cos<AF: AccelerateFloat>(
+    sin_out: &mut [AF], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, sin_out)?;
+    for (in_chunk, sin_chunk) in input.chunks_mut(CHUNK).zip(sin_out.chunks_mut(CHUNK)) {
+        let count = in_chunk.len() as i32;
+        unsafe {
+            AF::accelerate_sinpi(sin_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count);
+            AF::accelerate_cospi(in_chunk.as_mut_ptr(), in_chunk.as_ptr(), &count);
+        }
+    }
+    Ok(())
+}
+
+// ── Degree-based trigonometry ──
+
+/// Computes the sine and cosine of a tile of angles in degrees into `sin` and `cos`.
+///
+/// Each angle is reduced exactly to a remainder in [-45, 45] degrees and a quadrant before the
+/// `sinpi` and `cospi` kernels are called, so that results at multiples of 30, 45 and 90
+/// degrees are exact (up to the rounding of sqrt(2)/2 and sqrt(3)/2).
+fn sincosd_tile<AF: AccelerateFloat>(x: &[AF], sin: &mut [AF], cos: &mut [AF]) {
+    let count = x.len() as i32;
+    let full_turns = [AF::from_f64(360.0); TILE];
+    let mut quadrants = [0u8; TILE];
+    // fmod is exact, so this only discards whole turns
+    unsafe { AF::accelerate_fmod(sin.as_mut_ptr(), x.as_ptr(), full_turns.as_ptr(), &count); }
+    for (s, q) in sin.iter_mut().zip(quadrants.iter_mut()) {
+        let r = s.to_f64();
+        let k = (r / 90.0 + 0.5f64.copysign(r)) as i32;
+        // exact by Sterbenz's lemma, as r lies within 45 degrees of 90k
+        let rem = r - 90.0 * k as f64;
+        *q = k.rem_euclid(4) as u8 | match rem.abs() { 30.0 => 4, 45.0 => 8, _ => 0 };
+        *s = AF::from_f64(rem / 180.0);
+    }
+    cos.copy_from_slice(sin);
+    unsafe {
+        AF::accelerate_sinpi(sin.as_mut_ptr(), sin.as_ptr(), &count);
+        AF::accelerate_cospi(cos.as_mut_ptr(), cos.as_ptr(), &count);
+    }
+    let half = AF::from_f64(0.5);
+    for ((s, c), &q) in sin.iter_mut().zip(cos.iter_mut()).zip(quadrants.iter()) {
+        // sinpi(1/6) is not guaranteed to round to exactly 1/2, since 1/6 is itself rounded, and
+        // sinpi(1/4) and cospi(1/4) are not guaranteed to round identically
+        let sr = match q & 12 {
+            4 => if *s < AF::ZERO { -half } else { half },
+            8 => if *s < AF::ZERO { -*c } else { *c },
+            _ => *s,
+        };
+        let cr = *c;
+        (*s, *c) = match q & 3 {
+            0 => (sr, cr),
+            1 => (cr, -sr),
+            2 => (-sr, -cr),
+            _ => (-cr, sr),
+        };
+    }
+}
+
+/// Evaluates `pick(sin, cos)` for each angle in degrees, writing the results to `out`.
+fn degrees_map<AF: AccelerateFloat>(out: &mut [AF], input: &[AF], pick: fn(AF, AF) -> AF) {
+    let mut sin = [AF::ZERO; TILE];
+    let mut cos = [AF::ZERO; TILE];
+    for (out_chunk, in_chunk) in out.chunks_mut(TILE).zip(input.chunks(TILE)) {
+        let n = in_chunk.len();
+        sincosd_tile(in_chunk, &mut sin[..n], &mut cos[..n]);
+        for (o, (&s, &c)) in out_chunk.iter_mut().zip(sin.iter().zip(cos.iter())) {
+            *o = pick(s, c);
+        }
+    }
+}
+
+/// Evaluates `pick(sin, cos)` for each angle in degrees, overwriting `input` with the results.
+fn degrees_map_in_place<AF: AccelerateFloat>(input: &mut [AF], pick: fn(AF, AF) -> AF) {
+    let mut sin = [AF::ZERO; TILE];
+    let mut cos = [AF::ZERO; TILE];
+    for chunk in input.chunks_mut(TILE) {
+        let n = chunk.len();
+        sincosd_tile(chunk, &mut sin[..n], &mut cos[..n]);
+        for (o, (&s, &c)) in chunk.iter_mut().zip(sin.iter().zip(cos.iter())) {
+            *o = pick(s, c);
+        }
+    }
+}
+
+/// Converts an angle in radians to degrees. If `exact` is set, the caller knows the true
+/// result is a multiple of `step` degrees, and the converted value is rounded to it.
+fn to_degrees<AF: AccelerateFloat>(rad: AF, exact: bool, step: f64) -> AF {
+    let deg = rad.to_f64() * (180.0 / core::f64::consts::PI);
+    if exact && rad.is_finite() && rad != AF::ZERO {
+        let k = (deg / step + 0.5f64.copysign(deg)) as i32;
+        AF::from_f64(k as f64 * step)
+    } else {
+        AF::from_f64(deg)
+    }
+}
+
+/// Applies an inverse trigonometric kernel to `values`, which holds the inputs on entry, and
+/// converts the results to degrees. Inputs for which `is_exact` holds are rounded to the
+/// nearest multiple of `step` degrees.
+fn inverse_degrees_into<AF: AccelerateFloat>(
+    values: &mut [AF],
+    method: unsafe fn(*mut AF, *const AF, *const i32),
+    is_exact: fn(AF) -> bool,
+    step: f64,
+) {
+    let mut x_buf = [AF::ZERO; TILE];
+    for tile in values.chunks_mut(TILE) {
+        let x = &mut x_buf[..tile.len()];
+        x.copy_from_slice(tile);
+        let count = tile.len() as i32;
+        unsafe { method(tile.as_mut_ptr(), tile.as_ptr(), &count); }
+        for (t, &x) in tile.iter_mut().zip(x.iter()) {
+            *t = to_degrees(*t, is_exact(x), step);
+        }
+    }
+}
+
+/// Inputs whose arcsine and arccosine are exact multiples of 30 degrees
+fn is_exact_asin<AF: AccelerateFloat>(x: AF) -> bool {
+    let a = x.abs();
+    a == AF::ZERO || a == AF::from_f64(0.5) || a == AF::ONE
+}
+
+/// Inputs whose arctangent is an exact multiple of 45 degrees
+fn is_exact_atan<AF: AccelerateFloat>(x: AF) -> bool {
+    let a = x.abs();
+    a == AF::ZERO || a == AF::ONE || !a.is_finite()
+}
+
+/// Computes the sine of each element (in degrees), writing the results to `out`.
+///
+/// Results at multiples of 30 and 90 degrees are exact.
+///
+/// # Example
+///
+/// ```rust
+/// use vforce::trig::sind_array;
+/// let input: Vec<f64> = vec![30.0, 90.0, 180.0, 210.0];
+/// let mut out = vec![0.0f64; 4];
+/// sind_array(&mut out, &input).unwrap();
+/// assert_eq!(out, vec![0.5, 1.0, 0.0, -0.5]);
+/// ```
+pub fn sind_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    degrees_map(out, input, |s, _| s);
+    Ok(())
+}
+
+/// Computes the sine of each element (in degrees), overwriting `input` with the results.
+///
+/// Results at multiples of 30 and 90 degrees are exact.
+pub fn sind_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    degrees_map_in_place(input, |s, _| s);
+}
+
+/// Computes the cosine of each element (in degrees), writing the results to `out`.
+///
+/// Results at multiples of 60 and 90 degrees are exact.
+pub fn cosd_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    degrees_map(out, input, |_, c| c);
+    Ok(())
+}
+
+/// Computes the cosine of each element (in degrees), overwriting `input` with the results.
+///
+/// Results at multiples of 60 and 90 degrees are exact.
+pub fn cosd_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    degrees_map_in_place(input, |_, c| c);
+}
+
+/// Computes the tangent of each element (in degrees), writing the results to `out`.
+///
+/// Results at multiples of 45 degrees are exact, with odd multiples of 90 degrees giving +/-inf.
+pub fn tand_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    degrees_map(out, input, |s, c| s / c);
+    Ok(())
+}
+
+/// Computes the tangent of each element (in degrees), overwriting `input` with the results.
+///
+/// Results at multiples of 45 degrees are exact, with odd multiples of 90 degrees giving +/-inf.
+pub fn tand_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    degrees_map_in_place(input, |s, c| s / c);
+}
+
+/// Computes the sine and cosine of each element (in degrees), writing the results to `sin_out`
+/// and `cos_out` respectively.
+pub fn sincosd_array<AF: AccelerateFloat>(
+    sin_out: &mut [AF], cos_out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, sin_out, cos_out)?;
+    for ((sin_chunk, cos_chunk), in_chunk) in sin_out.chunks_mut(TILE)
+        .zip(cos_out.chunks_mut(TILE))
+        .zip(input.chunks(TILE))
+    {
+        sincosd_tile(in_chunk, sin_chunk, cos_chunk);
+    }
+    Ok(())
+}
+
+/// Computes the sine and cosine of each element (in degrees), writing the `sin` results into
+/// `input` and the `cos` results into `cos_out`
+pub fn sincosd_array_in_place_sin<AF: AccelerateFloat>(
+    cos_out: &mut [AF], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, cos_out)?;
+    let mut sin = [AF::ZERO; TILE];
+    for (in_chunk, cos_chunk) in input.chunks_mut(TILE).zip(cos_out.chunks_mut(TILE)) {
+        let n = in_chunk.len();
+        sincosd_tile(in_chunk, &mut sin[..n], cos_chunk);
+        in_chunk.copy_from_slice(&sin[..n]);
+    }
+    Ok(())
+}
+
+/// Computes the sine and cosine of each element (in degrees), writing the `cos` results into
+/// `input` and the `sin` results into `sin_out`
+pub fn sincosd_array_in_place_cos<AF: AccelerateFloat>(
+    sin_out: &mut [AF], input: &mut [AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, sin_out)?;
+    let mut cos = [AF::ZERO; TILE];
+    for (in_chunk, sin_chunk) in input.chunks_mut(TILE).zip(sin_out.chunks_mut(TILE)) {
+        let n = in_chunk.len();
+        sincosd_tile(in_chunk, sin_chunk, &mut cos[..n]);
+        in_chunk.copy_from_slice(&cos[..n]);
+    }
+    Ok(())
+}
+
+/// Computes the arcsine of each element in degrees, writing the results to `out`.
+///
+/// Inputs of 0, +/-0.5 and +/-1 give exactly 0, +/-30 and +/-90.
+/// If |x| > 1, the result is NaN.
+pub fn asind_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    inverse_degrees_into(out, AF::accelerate_asin, is_exact_asin, 30.0);
+    Ok(())
+}
+
+/// Computes the arcsine of each element in degrees, overwriting `input` with the results.
+///
+/// Inputs of 0, +/-0.5 and +/-1 give exactly 0, +/-30 and +/-90.
+/// If |x| > 1, the result is NaN.
+pub fn asind_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    inverse_degrees_into(input, AF::accelerate_asin, is_exact_asin, 30.0);
+}
+
+checked_unary_op!(
+    /// Computes the arcsine in degrees of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with |x| > 1 without writing anything.
+    asind_array_checked,
+    /// Computes the arcsine in degrees of each element, overwriting `input` with the results, or
+    /// returns `AccelerateError::DomainError` for the first element with |x| > 1 without modifying
+    /// `input`.
+    asind_array_in_place_checked,
+    asind_array, asind_array_in_place, crate::domain::Domain::UnitInterval);
+
+/// Computes the arccosine of each element in degrees, writing the results to `out`.
+///
+/// Inputs of 1, 0.5, 0, -0.5 and -1 give exactly 0, 60, 90, 120 and 180.
+/// If |x| > 1, the result is NaN.
+pub fn acosd_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    inverse_degrees_into(out, AF::accelerate_acos, is_exact_asin, 30.0);
+    Ok(())
+}
+
+/// Computes the arccosine of each element in degrees, overwriting `input` with the results.
+///
+/// Inputs of 1, 0.5, 0, -0.5 and -1 give exactly 0, 60, 90, 120 and 180.
+/// If |x| > 1, the result is NaN.
+pub fn acosd_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    inverse_degrees_into(input, AF::accelerate_acos, is_exact_asin, 30.0);
+}
+
+checked_unary_op!(
+    /// Computes the arccosine in degrees of each element, writing the results to `out`, or returns
+    /// `AccelerateError::DomainError` for the first element with |x| > 1 without writing anything.
+    acosd_array_checked,
+    /// Computes the arccosine in degrees of each element, overwriting `input` with the results, or
+    /// returns `AccelerateError::DomainError` for the first element with |x| > 1 without modifying
+    /// `input`.
+    acosd_array_in_place_checked,
+    acosd_array, acosd_array_in_place, crate::domain::Domain::UnitInterval);
+
+/// Computes the arctangent of each element in degrees, writing the results to `out`.
+///
+/// Inputs of 0, +/-1 and +/-inf give exactly 0, +/-45 and +/-90.
+pub fn atand_array<AF: AccelerateFloat>(
+    out: &mut [AF], input: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(input, out)?;
+    out.copy_from_slice(input);
+    inverse_degrees_into(out, AF::accelerate_atan, is_exact_atan, 45.0);
+    Ok(())
+}
+
+/// Computes the arctangent of each element in degrees, overwriting `input` with the results.
+///
+/// Inputs of 0, +/-1 and +/-inf give exactly 0, +/-45 and +/-90.
+pub fn atand_array_in_place<AF: AccelerateFloat>(input: &mut [AF]) {
+    inverse_degrees_into(input, AF::accelerate_atan, is_exact_atan, 45.0);
+}
+
+/// Pairs whose two-argument arctangent is an exact multiple of 45 degrees: those on the axes or
+/// the diagonals, including the infinite ones
+fn is_exact_atan2<AF: AccelerateFloat>(y: AF, x: AF) -> bool {
+    y == AF::ZERO || x == AF::ZERO || y.abs() == x.abs() || !y.is_finite() || !x.is_finite()
+}
+
+/// Computes the two-argument arctangent atan2(y, x) in degrees, writing the results to `out`.
+///
+/// Points on the axes and diagonals give exact multiples of 45 degrees. The special values
+/// follow those of [`atan2_array`], converted to degrees.
+pub fn atan2d_array<AF: AccelerateFloat>(
+    out: &mut [AF], y: &[AF], x: &[AF]
+) -> Result<(), AccelerateError> {
+    atan2_array(out, y, x)?;
+    for (o, (&y, &x)) in out.iter_mut().zip(y.iter().zip(x)) {
+        *o = to_degrees(*o, is_exact_atan2(y, x), 45.0);
+    }
+    Ok(())
+}
+
+/// Computes the two-argument arctangent atan2(y, x) in degrees, overwriting `y` with the results.
+///
+/// Points on the axes and diagonals give exact multiples of 45 degrees. The special values
+/// follow those of [`atan2_array`], converted to degrees.
+pub fn atan2d_array_in_place<AF: AccelerateFloat>(
+    y: &mut [AF], x: &[AF]
+) -> Result<(), AccelerateError> {
+    check_lengths!(y, x)?;
+    let mut y_buf = [AF::ZERO; TILE];
+    for (y_chunk, x_chunk) in y.chunks_mut(TILE).zip(x.chunks(TILE)) {
+        let y_copy = &mut y_buf[..y_chunk.len()];
+        y_copy.copy_from_slice(y_chunk);
+        let count = y_chunk.len() as i32;
+        unsafe { AF::accelerate_atan2(y_chunk.as_mut_ptr(), y_chunk.as_ptr(), x_chunk.as_ptr(), &count); }
+        for (o, (&y, &x)) in y_chunk.iter_mut().zip(y_copy.iter().zip(x_chunk)) {
+            *o = to_degrees(*o, is_exact_atan2(y, x), 45.0);
+        }
+    }
+    Ok(())
+}
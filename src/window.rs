@@ -0,0 +1,182 @@
+//! Window functions for spectral analysis and filter design, and their application to signals
+//!
+//! Each function fills `out` with a window of `out.len()` points, either symmetric, as for filter
+//! design, or periodic, as for spectral analysis with the DFT, in which case it is the symmetric
+//! window of one more point without its last point. A window of a single point is `[1.0]`.
+//!
+//! ```rust
+//! use vforce::window::{apply_window_in_place, hann, Symmetry};
+//!
+//! let mut window = [0.0f64; 5];
+//! hann(&mut window, Symmetry::Symmetric);
+//! assert!((window[2] - 1.0).abs() < 1e-15);
+//!
+//! let mut signal = [2.0f64; 5];
+//! apply_window_in_place(&mut signal, &window).unwrap();
+//! assert!((signal[1] - 1.0).abs() < 1e-15);
+//! ```
+use super::*;
+use crate::arithmetic::{mul_array, mul_array_in_place};
+use crate::exponential::exp_array_in_place;
+use crate::trig::cospi_array_in_place;
+
+/// Whether a window is symmetric or periodic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    /// Symmetric about its centre, with equal first and last points
+    Symmetric,
+    /// Periodic in its length, as the first `len` points of the symmetric window of `len + 1`
+    /// points
+    Periodic,
+}
+
+/// Returns the number of intervals the window spans, so that point `n` lies at `n / m` of the
+/// way across it.
+fn intervals(len: usize, symmetry: Symmetry) -> usize {
+    match symmetry {
+        Symmetry::Symmetric => len.saturating_sub(1),
+        Symmetry::Periodic => len,
+    }
+}
+
+/// Fills `out` with the generalized cosine window sum((-1)^k coeffs[k] cos(2 pi k n / m)), of
+/// which the Hann, Hamming, Blackman, Blackman-Harris and flat-top windows are instances.
+pub fn general_cosine<AF: AccelerateFloat>(out: &mut [AF], coeffs: &[AF], symmetry: Symmetry) {
+    if out.len() == 1 {
+        out[0] = AF::ONE;
+        return;
+    }
+    let m = intervals(out.len(), symmetry);
+    let mut cos = [AF::ZERO; TILE];
+    for (t, tile) in out.chunks_mut(TILE).enumerate() {
+        tile.fill(coeffs.first().copied().unwrap_or(AF::ZERO));
+        let cos = &mut cos[..tile.len()];
+        for (k, &a) in coeffs.iter().enumerate().skip(1) {
+            // 2kn/m reduced modulo 2 in integers, so that the argument is exact up to one rounding
+            for (n, c) in (t * TILE..).zip(cos.iter_mut()) {
+                let turns = (2 * k as u128 * n as u128 % (2 * m as u128)) as f64;
+                *c = AF::from_f64(turns / m as f64);
+            }
+            cospi_array_in_place(cos);
+            let a = if k % 2 == 1 { -a } else { a };
+            for (o, &c) in tile.iter_mut().zip(cos.iter()) {
+                *o = a.mul_add(c, *o);
+            }
+        }
+    }
+}
+
+/// Fills `out` with the Hann window, 0.5 - 0.5 cos(2 pi n / m).
+pub fn hann<AF: AccelerateFloat>(out: &mut [AF], symmetry: Symmetry) {
+    general_cosine(out, &[AF::from_f64(0.5), AF::from_f64(0.5)], symmetry);
+}
+
+/// Fills `out` with the Hamming window, 0.54 - 0.46 cos(2 pi n / m).
+pub fn hamming<AF: AccelerateFloat>(out: &mut [AF], symmetry: Symmetry) {
+    general_cosine(out, &[AF::from_f64(0.54), AF::from_f64(0.46)], symmetry);
+}
+
+/// Fills `out` with the Blackman window, with coefficients 0.42, 0.5 and 0.08.
+pub fn blackman<AF: AccelerateFloat>(out: &mut [AF], symmetry: Symmetry) {
+    general_cosine(out, &[0.42, 0.5, 0.08].map(AF::from_f64), symmetry);
+}
+
+/// Fills `out` with the four-term minimum Blackman-Harris window.
+pub fn blackman_harris<AF: AccelerateFloat>(out: &mut [AF], symmetry: Symmetry) {
+    general_cosine(out, &[0.35875, 0.48829, 0.14128, 0.01168].map(AF::from_f64), symmetry);
+}
+
+/// Fills `out` with the five-term flat-top window, with the coefficients used by SciPy, whose
+/// peak is very slightly above one.
+pub fn flat_top<AF: AccelerateFloat>(out: &mut [AF], symmetry: Symmetry) {
+    let coeffs = [0.21557895, 0.41663158, 0.277263158, 0.083578947, 0.006947368];
+    general_cosine(out, &coeffs.map(AF::from_f64), symmetry);
+}
+
+/// Fills `out` with the Tukey window, which tapers as a Hann window over a fraction `alpha` of
+/// its width, split between its two ends, and is one in between.
+///
+/// An `alpha` of zero or less gives the rectangular window, and of one or more the Hann window.
+pub fn tukey<AF: AccelerateFloat>(out: &mut [AF], alpha: AF, symmetry: Symmetry) {
+    if out.len() == 1 {
+        out[0] = AF::ONE;
+        return;
+    }
+    let m = intervals(out.len(), symmetry) as f64;
+    let alpha = alpha.to_f64().clamp(0.0, 1.0);
+    let half = AF::from_f64(0.5);
+    for (t, tile) in out.chunks_mut(TILE).enumerate() {
+        for (n, o) in (t * TILE..).zip(tile.iter_mut()) {
+            // distance from the nearer end, and the fraction of the taper it lies across
+            let d = (n as f64).min(m - n as f64);
+            *o = AF::from_f64(if d < alpha * m / 2.0 { 2.0 * d / (alpha * m) } else { 1.0 });
+        }
+        cospi_array_in_place(tile);
+        for o in tile.iter_mut() {
+            *o = half - half * *o;
+        }
+    }
+}
+
+/// Fills `out` with the Gaussian window exp(-((n - m / 2) / std)^2 / 2), with `std` in points.
+pub fn gaussian<AF: AccelerateFloat>(out: &mut [AF], std: AF, symmetry: Symmetry) {
+    if out.len() == 1 {
+        out[0] = AF::ONE;
+        return;
+    }
+    let centre = intervals(out.len(), symmetry) as f64 / 2.0;
+    let std = std.to_f64();
+    for (t, tile) in out.chunks_mut(TILE).enumerate() {
+        for (n, o) in (t * TILE..).zip(tile.iter_mut()) {
+            let z = (n as f64 - centre) / std;
+            *o = AF::from_f64(-0.5 * z * z);
+        }
+        exp_array_in_place(tile);
+    }
+}
+
+/// Returns the modified Bessel function of the first kind of order zero, I0(x), summing its power
+/// series to full precision.
+fn bessel_i0(x: f64) -> f64 {
+    let q = x * x / 4.0;
+    let (mut sum, mut term, mut k) = (1.0, 1.0, 1.0);
+    while term > sum * f64::EPSILON {
+        term *= q / (k * k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+/// Fills `out` with the Kaiser window I0(beta sqrt(1 - (2n / m - 1)^2)) / I0(beta), where I0 is
+/// the modified Bessel function of the first kind of order zero.
+///
+/// A `beta` of zero gives the rectangular window, and larger values narrower windows with lower
+/// sidelobes; 8.6 gives sidelobes close to those of the Blackman window.
+pub fn kaiser<AF: AccelerateFloat>(out: &mut [AF], beta: AF, symmetry: Symmetry) {
+    if out.len() == 1 {
+        out[0] = AF::ONE;
+        return;
+    }
+    let m = intervals(out.len(), symmetry) as f64;
+    let beta = beta.to_f64();
+    let norm = bessel_i0(beta);
+    for (n, o) in out.iter_mut().enumerate() {
+        let r = 2.0 * n as f64 / m - 1.0;
+        *o = AF::from_f64(bessel_i0(beta * AccelerateFloat::sqrt((1.0 - r * r).max(0.0))) / norm);
+    }
+}
+
+/// Multiplies `signal` by `window` elementwise, writing the results to `out`.
+pub fn apply_window<AF: AccelerateFloat>(
+    out: &mut [AF], signal: &[AF], window: &[AF]
+) -> Result<(), AccelerateError> {
+    mul_array(out, signal, window)
+}
+
+/// Multiplies `signal` by `window` elementwise, overwriting `signal` with the results.
+pub fn apply_window_in_place<AF: AccelerateFloat>(
+    signal: &mut [AF], window: &[AF]
+) -> Result<(), AccelerateError> {
+    mul_array_in_place(signal, window)
+}